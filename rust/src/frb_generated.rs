@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1474246620;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -49290677;
 
 // Section: executor
 
@@ -92,6 +92,52 @@ fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_tag_impl
         },
     )
 }
+fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_truncated_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "DicomElement_auto_accessor_get_truncated",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(api_that_guard.truncated.clone())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_value_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -235,14 +281,14 @@ fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_tag_impl
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_value_impl(
+fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_truncated_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DicomElement_auto_accessor_set_value",
+            debug_name: "DicomElement_auto_accessor_set_truncated",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -259,7 +305,7 @@ fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_value_im
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>,
             >>::sse_decode(&mut deserializer);
-            let api_value = <String>::sse_decode(&mut deserializer);
+            let api_truncated = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -278,7 +324,7 @@ fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_value_im
                 let mut api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
                     {
-                        api_that_guard.value = api_value;
+                        api_that_guard.truncated = api_truncated;
                     };
                 })?;
                 Ok(output_ok)
@@ -286,14 +332,14 @@ fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_value_im
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_vr_impl(
+fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_value_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "DicomElement_auto_accessor_set_vr",
+            debug_name: "DicomElement_auto_accessor_set_value",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -310,7 +356,7 @@ fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_vr_impl(
             let api_that = <RustOpaqueMoi<
                 flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>,
             >>::sse_decode(&mut deserializer);
-            let api_vr = <String>::sse_decode(&mut deserializer);
+            let api_value = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let mut api_that_guard = None;
@@ -329,7 +375,7 @@ fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_vr_impl(
                 let mut api_that_guard = api_that_guard.unwrap();
                 let output_ok = Result::<_, ()>::Ok({
                     {
-                        api_that_guard.vr = api_vr;
+                        api_that_guard.value = api_value;
                     };
                 })?;
                 Ok(output_ok)
@@ -337,17 +383,16 @@ fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_vr_impl(
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__dicom_handler_default_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_vr_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dicom_handler_default",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "DicomElement_auto_accessor_set_vr",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -359,29 +404,46 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>,
+            >>::sse_decode(&mut deserializer);
+            let api_vr = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::dicom_rs_interface::DicomHandler::default(),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    {
+                        api_that_guard.vr = api_vr;
+                    };
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__dicom_rs_interface__NestedElement_auto_accessor_get_element_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dicom_handler_extract_pixel_data",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "NestedElement_auto_accessor_get_element",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -393,33 +455,41 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that =
-                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok =
-                        crate::api::dicom_rs_interface::DicomHandler::extract_pixel_data(
-                            &api_that, api_bytes,
-                        )?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(api_that_guard.element.clone())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__dicom_rs_interface__NestedElement_auto_accessor_get_path_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dicom_handler_get_image_bytes",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "NestedElement_auto_accessor_get_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -431,32 +501,41 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that =
-                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_image_bytes(
-                        &api_that, api_bytes,
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok(api_that_guard.path.clone())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__dicom_handler_get_metadata_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__dicom_rs_interface__NestedElement_auto_accessor_set_element_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dicom_handler_get_metadata",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "NestedElement_auto_accessor_set_element",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -468,32 +547,46 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_get_metadata_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that =
-                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>,
+            >>::sse_decode(&mut deserializer);
+            let api_element = <DicomElement>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_metadata(
-                        &api_that, api_bytes,
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    {
+                        api_that_guard.element = api_element;
+                    };
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__dicom_handler_is_dicom_file_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__dicom_rs_interface__NestedElement_auto_accessor_set_path_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dicom_handler_is_dicom_file",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "NestedElement_auto_accessor_set_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -505,24 +598,37 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_is_dicom_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that =
-                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
-            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_that = <RustOpaqueMoi<
+                flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>,
+            >>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::dicom_rs_interface::DicomHandler::is_dicom_file(
-                            &api_that, api_bytes,
+            transform_result_sse::<_, ()>((move || {
+                let mut api_that_guard = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, true,
                         ),
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_guard = Some(api_that.lockable_decode_sync_ref_mut()),
+                        _ => unreachable!(),
+                    }
+                }
+                let mut api_that_guard = api_that_guard.unwrap();
+                let output_ok = Result::<_, ()>::Ok({
+                    {
+                        api_that_guard.path = api_path;
+                    };
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_impl(
+fn wire__crate__api__dicom_rs_interface__dicom_handler_anonymize_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -530,7 +636,7 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dicom_handler_load_file",
+            debug_name: "dicom_handler_anonymize",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -549,17 +655,18 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_impl(
             let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::load_file(
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::anonymize(
                         &api_that, api_bytes,
                     )?;
                     Ok(output_ok)
-                })())
+                })(
+                ))
             }
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_with_image_impl(
+fn wire__crate__api__dicom_rs_interface__dicom_handler_anonymize_custom_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -567,7 +674,7 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_with_image_impl
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dicom_handler_load_file_with_image",
+            debug_name: "dicom_handler_anonymize_custom",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -584,20 +691,67 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_with_image_impl
             let api_that =
                 <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
             let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_remove_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_keep_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::anonymize_custom(
+                        &api_that,
+                        api_bytes,
+                        api_remove_tags,
+                        api_keep_tags,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_build_volume_preview_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_build_volume_preview",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_files = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_max_dim = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
                     let output_ok =
-                        crate::api::dicom_rs_interface::DicomHandler::load_file_with_image(
-                            &api_that, api_bytes,
+                        crate::api::dicom_rs_interface::DicomHandler::build_volume_preview(
+                            &api_that,
+                            api_files,
+                            api_max_dim,
                         )?;
                     Ok(output_ok)
-                })())
+                })(
+                ))
             }
         },
     )
 }
-fn wire__crate__api__dicom_rs_interface__dicom_handler_new_impl(
+fn wire__crate__api__dicom_rs_interface__dicom_handler_check_series_consistency_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -605,7 +759,7 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_new_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dicom_handler_new",
+            debug_name: "dicom_handler_check_series_consistency",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -619,657 +773,7268 @@ fn wire__crate__api__dicom_rs_interface__dicom_handler_new_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_files = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::dicom_rs_interface::DicomHandler::new())?;
+                        crate::api::dicom_rs_interface::DicomHandler::check_series_consistency(
+                            &api_that, api_files,
+                        )?;
                     Ok(output_ok)
-                })())
+                })(
+                ))
             }
         },
     )
 }
-
-// Section: related_funcs
-
-flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
-    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>
-);
-
-// Section: dart2rust
-
-impl SseDecode for DicomElement {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueMoi<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
-    }
-}
-
-impl SseDecode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return decode_rust_opaque_moi(inner);
-    }
+fn wire__crate__api__dicom_rs_interface__dicom_handler_check_tags_present_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_check_tags_present",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::check_tags_present(
+                            &api_that, api_bytes, api_tags,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__dicom_rs_interface__dicom_handler_count_frames_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_count_frames",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::count_frames(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire__crate__api__dicom_rs_interface__dicom_handler_count_instances_in_dir_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_count_instances_in_dir",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::count_instances_in_dir(
+                            &api_that, api_dir,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
 }
-
-impl SseDecode for crate::api::dicom_rs_interface::DicomFile {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_metadata =
-            <crate::api::dicom_rs_interface::DicomMetadata>::sse_decode(deserializer);
-        let mut var_image =
-            <Option<crate::api::dicom_rs_interface::DicomImage>>::sse_decode(deserializer);
-        let mut var_isValid = <bool>::sse_decode(deserializer);
-        return crate::api::dicom_rs_interface::DicomFile {
-            metadata: var_metadata,
-            image: var_image,
-            is_valid: var_isValid,
-        };
-    }
+fn wire__crate__api__dicom_rs_interface__dicom_handler_create_secondary_capture_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_create_secondary_capture",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_image_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_metadata =
+                <crate::api::dicom_rs_interface::DicomMetadata>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::create_secondary_capture(
+                            &api_that,
+                            api_image_bytes,
+                            api_metadata,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
 }
-
-impl SseDecode for crate::api::dicom_rs_interface::DicomHandler {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        return crate::api::dicom_rs_interface::DicomHandler {};
-    }
+fn wire__crate__api__dicom_rs_interface__dicom_handler_create_secondary_capture_from_reference_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "dicom_handler_create_secondary_capture_from_reference", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+let api_image_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_reference = <Vec<u8>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move ||  {
+                         let output_ok = crate::api::dicom_rs_interface::DicomHandler::create_secondary_capture_from_reference(&api_that, api_image_bytes, api_reference)?;   Ok(output_ok)
+                    })())
+                } })
 }
-
-impl SseDecode for crate::api::dicom_rs_interface::DicomImage {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_width = <u32>::sse_decode(deserializer);
-        let mut var_height = <u32>::sse_decode(deserializer);
+fn wire__crate__api__dicom_rs_interface__dicom_handler_crop_to_dicom_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_crop_to_dicom",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_x = <u32>::sse_decode(&mut deserializer);
+            let api_y = <u32>::sse_decode(&mut deserializer);
+            let api_w = <u32>::sse_decode(&mut deserializer);
+            let api_h = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::crop_to_dicom(
+                        &api_that, api_bytes, api_x, api_y, api_w, api_h,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::DicomHandler::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_distinct_tag_values_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_distinct_tag_values",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_files = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::distinct_tag_values(
+                            &api_that, api_files, api_tag,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_explode_multiframe_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_explode_multiframe",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::explode_multiframe(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_export_frame_montage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_export_frame_montage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_columns = <u32>::sse_decode(&mut deserializer);
+            let api_max_frame_dim = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::export_frame_montage(
+                            &api_that,
+                            api_bytes,
+                            api_columns,
+                            api_max_frame_dim,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_export_series_to_tiff_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_export_series_to_tiff",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_files = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::export_series_to_tiff(
+                            &api_that, api_files,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_icc_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_extract_icc_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::extract_icc_profile(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_extract_pixel_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::extract_pixel_data(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_extract_pixel_data_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::dicom_rs_interface::DicomHandler::extract_pixel_data_async(
+                                &api_that, api_bytes,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_f32_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_extract_pixel_data_f32",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::extract_pixel_data_f32(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_frame_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_extract_pixel_data_frame",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_frame = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::extract_pixel_data_frame(
+                            &api_that, api_bytes, api_frame,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixels_normalized_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_extract_pixels_normalized",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::extract_pixels_normalized(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixels_normalized_windowed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "dicom_handler_extract_pixels_normalized_windowed", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_center = <f64>::sse_decode(&mut deserializer);
+let api_width = <f64>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move ||  {
+                         let output_ok = crate::api::dicom_rs_interface::DicomHandler::extract_pixels_normalized_windowed(&api_that, api_bytes, api_center, api_width)?;   Ok(output_ok)
+                    })())
+                } })
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_generate_mip_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_generate_mip",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_files = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_axis = <crate::api::dicom_rs_interface::Axis>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::generate_mip(
+                        &api_that, api_files, api_axis,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_generate_uid_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_generate_uid",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_root = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::DicomHandler::generate_uid(
+                            &api_that, api_root,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_generate_uid_set_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_generate_uid_set",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_root = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::DicomHandler::generate_uid_set(
+                            &api_that, api_root,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_all_elements_recursive_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_all_elements_recursive",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_all_elements_recursive(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_compressed_pixel_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_compressed_pixel_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_compressed_pixel_data(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_elements_by_group_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_elements_by_group",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_group = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_elements_by_group(
+                            &api_that, api_bytes, api_group,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_encoding_info_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_encoding_info",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_encoding_info(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_file_meta_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_file_meta",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_file_meta(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_frame_increment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_frame_increment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_frame_increment(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_frames_range_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_frames_range",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_start = <u32>::sse_decode(&mut deserializer);
+            let api_count = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_frames_range(
+                        &api_that, api_bytes, api_start, api_count,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_hounsfield_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_hounsfield",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_x = <u32>::sse_decode(&mut deserializer);
+            let api_y = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_hounsfield(
+                        &api_that, api_bytes, api_x, api_y,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_image_bytes(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_16bit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_16bit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_16bit(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_16bit_png_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_16bit_png",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_16bit_png(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_as_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_as",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_format =
+                <crate::api::dicom_rs_interface::ImageOutputFormat>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_as(
+                            &api_that, api_bytes, api_format,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_async(
+                                &api_that, api_bytes,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_colormapped_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_colormapped",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_colormap =
+                <crate::api::dicom_rs_interface::ColorMap>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_colormapped(
+                            &api_that,
+                            api_bytes,
+                            api_colormap,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_equalized_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_equalized",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_equalized(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_force_photometric_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "dicom_handler_get_image_bytes_force_photometric", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+let api_photometric = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move ||  {
+                         let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_force_photometric(&api_that, api_bytes, api_photometric)?;   Ok(output_ok)
+                    })())
+                } })
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_oriented_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_oriented",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_oriented(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_partial_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_partial",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_partial(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_with_voi_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_bytes_with_voi",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_mode =
+                <crate::api::dicom_rs_interface::VoiLutMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_image_bytes_with_voi(
+                            &api_that, api_bytes, api_mode,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_image_region_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_image_region",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_region = <crate::api::dicom_rs_interface::Rect>::sse_decode(&mut deserializer);
+            let api_max_dim = <u32>::sse_decode(&mut deserializer);
+            let api_filter =
+                <crate::api::dicom_rs_interface::ResizeFilter>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_image_region(
+                        &api_that,
+                        api_bytes,
+                        api_region,
+                        api_max_dim,
+                        api_filter,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_key_object_selection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_key_object_selection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_key_object_selection(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_metadata(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_metadata_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_metadata_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::dicom_rs_interface::DicomHandler::get_metadata_async(
+                                &api_that, api_bytes,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_orientation_labels_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_orientation_labels",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_orientation_labels(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_pet_suv_params_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_pet_suv_params",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_pet_suv_params(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_pixel_statistics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_pixel_statistics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_pixel_statistics(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_print_calibration_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_print_calibration",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_print_calibration(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_provenance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_provenance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_provenance(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_referenced_instances_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_referenced_instances",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_referenced_instances(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_registration_matrices_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_registration_matrices",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_registration_matrices(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_rgba_buffer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_rgba_buffer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_rgba_buffer(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_sequences_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_sequences",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_sequences(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_series_cover_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_series_cover",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_files = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_max_dim = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_series_cover(
+                        &api_that,
+                        api_files,
+                        api_max_dim,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_sr_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_sr_text",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_sr_text(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_study_datetime_unix_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_study_datetime_unix",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_study_datetime_unix(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_thumbnail_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_thumbnail",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_max_dim = <u32>::sse_decode(&mut deserializer);
+            let api_filter =
+                <crate::api::dicom_rs_interface::ResizeFilter>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_thumbnail(
+                        &api_that,
+                        api_bytes,
+                        api_max_dim,
+                        api_filter,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_thumbnails_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_thumbnails_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_files = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_max_dim = <u32>::sse_decode(&mut deserializer);
+            let api_max_concurrency = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_thumbnails_batch(
+                            &api_that,
+                            api_files,
+                            api_max_dim,
+                            api_max_concurrency,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_ultrasound_regions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_ultrasound_regions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::get_ultrasound_regions(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_value_multi_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_value_multi",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_value_multi(
+                        &api_that, api_bytes, api_tag,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_get_waveforms_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_get_waveforms",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::get_waveforms(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_has_burned_in_annotation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_has_burned_in_annotation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::has_burned_in_annotation(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_has_pixel_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_has_pixel_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::has_pixel_data(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_is_dicom_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_is_dicom_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::DicomHandler::is_dicom_file(
+                            &api_that, api_bytes,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_load_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::load_file(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_load_file_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::dicom_rs_interface::DicomHandler::load_file_async(
+                                &api_that, api_bytes,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_with_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_load_file_with_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::load_file_with_image(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_load_file_with_image_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "dicom_handler_load_file_with_image_async", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || async move {
+                         let output_ok = crate::api::dicom_rs_interface::DicomHandler::load_file_with_image_async(&api_that, api_bytes).await?;   Ok(output_ok)
+                    })().await)
+                } })
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_merge_study_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_merge_study_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_metadatas =
+                <Vec<crate::api::dicom_rs_interface::DicomMetadata>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::merge_study_metadata(
+                            &api_that,
+                            api_metadatas,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_new_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_new",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::dicom_rs_interface::DicomHandler::new())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_normalize_date_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_normalize_date",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_raw = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::DicomHandler::normalize_date(
+                            &api_that, api_raw,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_normalize_time_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_normalize_time",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_raw = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::DicomHandler::normalize_time(
+                            &api_that, api_raw,
+                        ),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_peek_identifiers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_peek_identifiers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::peek_identifiers(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_probe_pixel_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_probe_pixel",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_frame = <u32>::sse_decode(&mut deserializer);
+            let api_x = <u32>::sse_decode(&mut deserializer);
+            let api_y = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::probe_pixel(
+                        &api_that, api_bytes, api_frame, api_x, api_y,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_redact_regions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_redact_regions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_rects =
+                <Vec<crate::api::dicom_rs_interface::Rect>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::redact_regions(
+                        &api_that, api_bytes, api_rects,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_resample_isotropic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_resample_isotropic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_target_spacing_mm = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::resample_isotropic(
+                            &api_that,
+                            api_bytes,
+                            api_target_spacing_mm,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_scan_directory_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_scan_directory_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_dir = <String>::sse_decode(&mut deserializer);
+            let api_on_file = decode_DartFn_Inputs_scanned_file_Output_unit_AnyhowException(
+                <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::dicom_rs_interface::DicomHandler::scan_directory_stream(
+                                &api_that,
+                                api_dir,
+                                api_on_file,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_search_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_search_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_keyword = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::search_tags(
+                        &api_that,
+                        api_bytes,
+                        api_keyword,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_split_multiframe_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_split_multiframe",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::split_multiframe(
+                        &api_that, api_bytes,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_stream_frames_from_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_stream_frames_from_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_on_frame =
+                decode_DartFn_Inputs_u_32_list_prim_u_8_strict_Output_unit_AnyhowException(
+                    <flutter_rust_bridge::DartOpaque>::sse_decode(&mut deserializer),
+                );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::dicom_rs_interface::DicomHandler::stream_frames_from_path(
+                                &api_that,
+                                api_path,
+                                api_on_frame,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_summarize_study_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_summarize_study",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_files = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::summarize_study(
+                        &api_that, api_files,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_tags_present_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_tags_present",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_tags = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::tags_present(
+                        &api_that, api_bytes, api_tags,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_verify_pixel_data_length_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_verify_pixel_data_length",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::verify_pixel_data_length(
+                            &api_that, api_bytes,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_wado_rs_retrieve_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_wado_rs_retrieve",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_study = <String>::sse_decode(&mut deserializer);
+            let api_series = <String>::sse_decode(&mut deserializer);
+            let api_instance = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::wado_rs_retrieve(
+                        &api_that,
+                        api_base_url,
+                        api_study,
+                        api_series,
+                        api_instance,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_wado_rs_retrieve_with_retry_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_wado_rs_retrieve_with_retry",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_base_url = <String>::sse_decode(&mut deserializer);
+            let api_study = <String>::sse_decode(&mut deserializer);
+            let api_series = <String>::sse_decode(&mut deserializer);
+            let api_instance = <String>::sse_decode(&mut deserializer);
+            let api_timeout_ms = <u64>::sse_decode(&mut deserializer);
+            let api_retries = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok =
+                        crate::api::dicom_rs_interface::DicomHandler::wado_rs_retrieve_with_retry(
+                            &api_that,
+                            api_base_url,
+                            api_study,
+                            api_series,
+                            api_instance,
+                            api_timeout_ms,
+                            api_retries,
+                        )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__dicom_handler_write_rgb_image_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dicom_handler_write_rgb_image",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that =
+                <crate::api::dicom_rs_interface::DicomHandler>::sse_decode(&mut deserializer);
+            let api_rgb = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_width = <u32>::sse_decode(&mut deserializer);
+            let api_height = <u32>::sse_decode(&mut deserializer);
+            let api_reference = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::api::dicom_rs_interface::DicomError>((move || {
+                    let output_ok = crate::api::dicom_rs_interface::DicomHandler::write_rgb_image(
+                        &api_that,
+                        api_rgb,
+                        api_width,
+                        api_height,
+                        api_reference,
+                    )?;
+                    Ok(output_ok)
+                })(
+                ))
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__parse_dicom_datetime_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_dicom_datetime",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_date = <Option<String>>::sse_decode(&mut deserializer);
+            let api_time = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::parse_dicom_datetime(api_date, api_time),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__resize_filter_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resize_filter_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::ResizeFilter::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__dicom_rs_interface__supported_transfer_syntaxes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "supported_transfer_syntaxes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::dicom_rs_interface::supported_transfer_syntaxes(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: related_funcs
+
+fn decode_DartFn_Inputs_scanned_file_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(crate::api::dicom_rs_interface::ScannedFile) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(
+        dart_opaque: flutter_rust_bridge::DartOpaque,
+        arg0: crate::api::dicom_rs_interface::ScannedFile,
+    ) -> () {
+        let args = vec![arg0.into_into_dart().into_dart()];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: crate::api::dicom_rs_interface::ScannedFile| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+        ))
+    }
+}
+fn decode_DartFn_Inputs_u_32_list_prim_u_8_strict_Output_unit_AnyhowException(
+    dart_opaque: flutter_rust_bridge::DartOpaque,
+) -> impl Fn(u32, Vec<u8>) -> flutter_rust_bridge::DartFnFuture<()> {
+    use flutter_rust_bridge::IntoDart;
+
+    async fn body(dart_opaque: flutter_rust_bridge::DartOpaque, arg0: u32, arg1: Vec<u8>) -> () {
+        let args = vec![
+            arg0.into_into_dart().into_dart(),
+            arg1.into_into_dart().into_dart(),
+        ];
+        let message = FLUTTER_RUST_BRIDGE_HANDLER
+            .dart_fn_invoke(dart_opaque, args)
+            .await;
+
+        let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+        let action = deserializer.cursor.read_u8().unwrap();
+        let ans = match action {
+            0 => std::result::Result::Ok(<()>::sse_decode(&mut deserializer)),
+            1 => std::result::Result::Err(
+                <flutter_rust_bridge::for_generated::anyhow::Error>::sse_decode(&mut deserializer),
+            ),
+            _ => unreachable!(),
+        };
+        deserializer.end();
+        let ans = ans.expect("Dart throws exception but Rust side assume it is not failable");
+        ans
+    }
+
+    move |arg0: u32, arg1: Vec<u8>| {
+        flutter_rust_bridge::for_generated::convert_into_dart_fn_future(body(
+            dart_opaque.clone(),
+            arg0,
+            arg1,
+        ))
+    }
+}
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>
+);
+flutter_rust_bridge::frb_generated_moi_arc_impl_value!(
+    flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>
+);
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for DicomElement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for NestedElement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueMoi<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { flutter_rust_bridge::for_generated::sse_decode_dart_opaque(inner) };
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return decode_rust_opaque_moi(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::Axis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::dicom_rs_interface::Axis::Axial,
+            1 => crate::api::dicom_rs_interface::Axis::Coronal,
+            2 => crate::api::dicom_rs_interface::Axis::Sagittal,
+            _ => unreachable!("Invalid variant for Axis: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::ColorMap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::dicom_rs_interface::ColorMap::Grayscale,
+            1 => crate::api::dicom_rs_interface::ColorMap::HotIron,
+            2 => crate::api::dicom_rs_interface::ColorMap::Jet,
+            3 => crate::api::dicom_rs_interface::ColorMap::Pet,
+            _ => unreachable!("Invalid variant for ColorMap: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::CompressedPixels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_transferSyntaxUid = <String>::sse_decode(deserializer);
+        let mut var_isCompressed = <bool>::sse_decode(deserializer);
+        let mut var_frames = <Vec<Vec<u8>>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::CompressedPixels {
+            transfer_syntax_uid: var_transferSyntaxUid,
+            is_compressed: var_isCompressed,
+            frames: var_frames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::ConsistencyReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_isConsistent = <bool>::sse_decode(deserializer);
+        let mut var_dimensionMismatches = <Vec<String>>::sse_decode(deserializer);
+        let mut var_sliceSpacings = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_uniformSpacing = <bool>::sse_decode(deserializer);
+        let mut var_gaps =
+            <Vec<crate::api::dicom_rs_interface::SliceGap>>::sse_decode(deserializer);
+        let mut var_mixedOrientations = <bool>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::ConsistencyReport {
+            is_consistent: var_isConsistent,
+            dimension_mismatches: var_dimensionMismatches,
+            slice_spacings: var_sliceSpacings,
+            uniform_spacing: var_uniformSpacing,
+            gaps: var_gaps,
+            mixed_orientations: var_mixedOrientations,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::DicomError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::Parse(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::Decode(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::Encode(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::TooLarge(var_field0);
+            }
+            4 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::MissingTag(var_field0);
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::UnsupportedTransferSyntax(
+                    var_field0,
+                );
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::InvalidArgument(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::Io(var_field0);
+            }
+            8 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::Network(var_field0);
+            }
+            9 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::DicomError::Other(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::DicomFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_metadata =
+            <crate::api::dicom_rs_interface::DicomMetadata>::sse_decode(deserializer);
+        let mut var_image =
+            <Option<crate::api::dicom_rs_interface::DicomImage>>::sse_decode(deserializer);
+        let mut var_isValid = <bool>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::DicomFile {
+            metadata: var_metadata,
+            image: var_image,
+            is_valid: var_isValid,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::DicomFloatImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_numberOfFrames = <u32>::sse_decode(deserializer);
+        let mut var_samplesPerPixel = <u16>::sse_decode(deserializer);
+        let mut var_pixelData = <Vec<f32>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::DicomFloatImage {
+            width: var_width,
+            height: var_height,
+            number_of_frames: var_numberOfFrames,
+            samples_per_pixel: var_samplesPerPixel,
+            pixel_data: var_pixelData,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::DicomHandler {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_strict = <bool>::sse_decode(deserializer);
+        let mut var_maxDecodedBytes = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxValueLength = <Option<usize>>::sse_decode(deserializer);
+        let mut var_embedIccProfile = <bool>::sse_decode(deserializer);
+        let mut var_threadPoolSize = <Option<usize>>::sse_decode(deserializer);
+        let mut var_excludePixelPadding = <bool>::sse_decode(deserializer);
+        let mut var_windowExcludesPadding = <bool>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::DicomHandler {
+            strict: var_strict,
+            max_decoded_bytes: var_maxDecodedBytes,
+            max_value_length: var_maxValueLength,
+            embed_icc_profile: var_embedIccProfile,
+            thread_pool_size: var_threadPoolSize,
+            exclude_pixel_padding: var_excludePixelPadding,
+            window_excludes_padding: var_windowExcludesPadding,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::DicomImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
         let mut var_bitsAllocated = <u16>::sse_decode(deserializer);
         let mut var_bitsStored = <u16>::sse_decode(deserializer);
         let mut var_pixelRepresentation = <u16>::sse_decode(deserializer);
         let mut var_photometricInterpretation = <String>::sse_decode(deserializer);
         let mut var_samplesPerPixel = <u16>::sse_decode(deserializer);
         let mut var_pixelData = <Vec<u8>>::sse_decode(deserializer);
-        return crate::api::dicom_rs_interface::DicomImage {
+        let mut var_frameIndex = <u32>::sse_decode(deserializer);
+        let mut var_bytesPerFrame = <usize>::sse_decode(deserializer);
+        let mut var_appliedInvert = <bool>::sse_decode(deserializer);
+        let mut var_appliedWindow = <Option<(f64, f64)>>::sse_decode(deserializer);
+        let mut var_warnings = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::DicomImage {
+            width: var_width,
+            height: var_height,
+            bits_allocated: var_bitsAllocated,
+            bits_stored: var_bitsStored,
+            pixel_representation: var_pixelRepresentation,
+            photometric_interpretation: var_photometricInterpretation,
+            samples_per_pixel: var_samplesPerPixel,
+            pixel_data: var_pixelData,
+            frame_index: var_frameIndex,
+            bytes_per_frame: var_bytesPerFrame,
+            applied_invert: var_appliedInvert,
+            applied_window: var_appliedWindow,
+            warnings: var_warnings,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::DicomMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_patientName = <Option<String>>::sse_decode(deserializer);
+        let mut var_patientId = <Option<String>>::sse_decode(deserializer);
+        let mut var_studyDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_studyDateNormalized = <Option<String>>::sse_decode(deserializer);
+        let mut var_accessionNumber = <Option<String>>::sse_decode(deserializer);
+        let mut var_referringPhysician = <Option<String>>::sse_decode(deserializer);
+        let mut var_modality = <Option<String>>::sse_decode(deserializer);
+        let mut var_studyDescription = <Option<String>>::sse_decode(deserializer);
+        let mut var_seriesDescription = <Option<String>>::sse_decode(deserializer);
+        let mut var_instanceNumber = <Option<i32>>::sse_decode(deserializer);
+        let mut var_seriesNumber = <Option<i32>>::sse_decode(deserializer);
+        let mut var_studyInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_seriesInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_sopInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_frameOfReferenceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_imagePosition = <Option<Vec<f64>>>::sse_decode(deserializer);
+        let mut var_pixelSpacing = <Option<Vec<f64>>>::sse_decode(deserializer);
+        let mut var_sliceLocation = <Option<f64>>::sse_decode(deserializer);
+        let mut var_sliceThickness = <Option<f64>>::sse_decode(deserializer);
+        let mut var_burnedInAnnotation = <Option<bool>>::sse_decode(deserializer);
+        let mut var_pixelAspectRatio = <Option<[u32; 2]>>::sse_decode(deserializer);
+        let mut var_contrastBolusAgent = <Option<String>>::sse_decode(deserializer);
+        let mut var_protocolName = <Option<String>>::sse_decode(deserializer);
+        let mut var_bodyPartExamined = <Option<String>>::sse_decode(deserializer);
+        let mut var_laterality = <Option<String>>::sse_decode(deserializer);
+        let mut var_viewPosition = <Option<String>>::sse_decode(deserializer);
+        let mut var_sopClassUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_sopClassName = <Option<String>>::sse_decode(deserializer);
+        let mut var_ctdiVol = <Option<f64>>::sse_decode(deserializer);
+        let mut var_exposure = <Option<f64>>::sse_decode(deserializer);
+        let mut var_kvp = <Option<f64>>::sse_decode(deserializer);
+        let mut var_exposureTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_rdsrTotalDlp = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::DicomMetadata {
+            patient_name: var_patientName,
+            patient_id: var_patientId,
+            study_date: var_studyDate,
+            study_date_normalized: var_studyDateNormalized,
+            accession_number: var_accessionNumber,
+            referring_physician: var_referringPhysician,
+            modality: var_modality,
+            study_description: var_studyDescription,
+            series_description: var_seriesDescription,
+            instance_number: var_instanceNumber,
+            series_number: var_seriesNumber,
+            study_instance_uid: var_studyInstanceUid,
+            series_instance_uid: var_seriesInstanceUid,
+            sop_instance_uid: var_sopInstanceUid,
+            frame_of_reference_uid: var_frameOfReferenceUid,
+            image_position: var_imagePosition,
+            pixel_spacing: var_pixelSpacing,
+            slice_location: var_sliceLocation,
+            slice_thickness: var_sliceThickness,
+            burned_in_annotation: var_burnedInAnnotation,
+            pixel_aspect_ratio: var_pixelAspectRatio,
+            contrast_bolus_agent: var_contrastBolusAgent,
+            protocol_name: var_protocolName,
+            body_part_examined: var_bodyPartExamined,
+            laterality: var_laterality,
+            view_position: var_viewPosition,
+            sop_class_uid: var_sopClassUid,
+            sop_class_name: var_sopClassName,
+            ctdi_vol: var_ctdiVol,
+            exposure: var_exposure,
+            kvp: var_kvp,
+            exposure_time: var_exposureTime,
+            rdsr_total_dlp: var_rdsrTotalDlp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::EncodingInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_transferSyntaxUid = <String>::sse_decode(deserializer);
+        let mut var_isExplicitVr = <bool>::sse_decode(deserializer);
+        let mut var_isLittleEndian = <bool>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::EncodingInfo {
+            transfer_syntax_uid: var_transferSyntaxUid,
+            is_explicit_vr: var_isExplicitVr,
+            is_little_endian: var_isLittleEndian,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::FrameIncrement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tag = <String>::sse_decode(deserializer);
+        let mut var_alias = <String>::sse_decode(deserializer);
+        let mut var_values = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::FrameIncrement {
+            tag: var_tag,
+            alias: var_alias,
+            values: var_values,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::FrameIncrementInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_numberOfFrames = <u32>::sse_decode(deserializer);
+        let mut var_increments =
+            <Vec<crate::api::dicom_rs_interface::FrameIncrement>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::FrameIncrementInfo {
+            number_of_frames: var_numberOfFrames,
+            increments: var_increments,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::FrameMontage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_png = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_cellWidth = <u32>::sse_decode(deserializer);
+        let mut var_cellHeight = <u32>::sse_decode(deserializer);
+        let mut var_columns = <u32>::sse_decode(deserializer);
+        let mut var_rows = <u32>::sse_decode(deserializer);
+        let mut var_frameCount = <u32>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::FrameMontage {
+            png: var_png,
+            cell_width: var_cellWidth,
+            cell_height: var_cellHeight,
+            columns: var_columns,
+            rows: var_rows,
+            frame_count: var_frameCount,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::ImageOutputFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::dicom_rs_interface::ImageOutputFormat::Png;
+            }
+            1 => {
+                let mut var_quality = <u8>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::ImageOutputFormat::Jpeg {
+                    quality: var_quality,
+                };
+            }
+            2 => {
+                return crate::api::dicom_rs_interface::ImageOutputFormat::WebP;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::KeyImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_studyInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_seriesInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_sopClassUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_sopInstanceUid = <String>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::KeyImage {
+            study_instance_uid: var_studyInstanceUid,
+            series_instance_uid: var_seriesInstanceUid,
+            sop_class_uid: var_sopClassUid,
+            sop_instance_uid: var_sopInstanceUid,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::KeyObjectSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_titleCode = <Option<String>>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_keyImages =
+            <Vec<crate::api::dicom_rs_interface::KeyImage>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::KeyObjectSelection {
+            title_code: var_titleCode,
+            title: var_title,
+            key_images: var_keyImages,
+        };
+    }
+}
+
+impl SseDecode for Vec<DicomElement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<DicomElement>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<NestedElement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<NestedElement>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<bool>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::DicomMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::DicomMetadata>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::FrameIncrement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::FrameIncrement>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::KeyImage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::KeyImage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<u8>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::Rect> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::Rect>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::ReferencedInstance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::dicom_rs_interface::ReferencedInstance>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::RegistrationMatrix> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::dicom_rs_interface::RegistrationMatrix>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::SequenceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::SequenceInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::SliceGap> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::SliceGap>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::UsRegion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::UsRegion>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::Waveform> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::Waveform>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::dicom_rs_interface::WaveformChannel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::dicom_rs_interface::WaveformChannel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::NormalizedPixels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_numberOfFrames = <u32>::sse_decode(deserializer);
+        let mut var_samplesPerPixel = <u16>::sse_decode(deserializer);
+        let mut var_pixelData = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_min = <f32>::sse_decode(deserializer);
+        let mut var_max = <f32>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::NormalizedPixels {
+            width: var_width,
+            height: var_height,
+            number_of_frames: var_numberOfFrames,
+            samples_per_pixel: var_samplesPerPixel,
+            pixel_data: var_pixelData,
+            min: var_min,
+            max: var_max,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::dicom_rs_interface::DicomImage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::dicom_rs_interface::DicomImage>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::dicom_rs_interface::DicomMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::dicom_rs_interface::DicomMetadata>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::dicom_rs_interface::FrameIncrementInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::dicom_rs_interface::FrameIncrementInfo>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::dicom_rs_interface::PrintCalibration> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::dicom_rs_interface::PrintCalibration>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<(f64, f64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<(f64, f64)>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<f64>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<f64>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<u8>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<[u32; 2]> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<[u32; 2]>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::OrientationLabels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_left = <String>::sse_decode(deserializer);
+        let mut var_right = <String>::sse_decode(deserializer);
+        let mut var_top = <String>::sse_decode(deserializer);
+        let mut var_bottom = <String>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::OrientationLabels {
+            left: var_left,
+            right: var_right,
+            top: var_top,
+            bottom: var_bottom,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::PartialImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_png = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_isPartial = <bool>::sse_decode(deserializer);
+        let mut var_completeRows = <u32>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::PartialImage {
+            png: var_png,
+            is_partial: var_isPartial,
+            complete_rows: var_completeRows,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::PixelLengthReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_isEncapsulated = <bool>::sse_decode(deserializer);
+        let mut var_expected = <Option<u64>>::sse_decode(deserializer);
+        let mut var_actual = <u64>::sse_decode(deserializer);
+        let mut var_fragmentCount = <Option<u32>>::sse_decode(deserializer);
+        let mut var_matches = <bool>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::PixelLengthReport {
+            is_encapsulated: var_isEncapsulated,
+            expected: var_expected,
+            actual: var_actual,
+            fragment_count: var_fragmentCount,
+            matches: var_matches,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::PixelStatistics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_min = <f64>::sse_decode(deserializer);
+        let mut var_max = <f64>::sse_decode(deserializer);
+        let mut var_mean = <f64>::sse_decode(deserializer);
+        let mut var_stdDev = <f64>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        let mut var_paddingExcluded = <bool>::sse_decode(deserializer);
+        let mut var_paddingPixels = <u64>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::PixelStatistics {
+            min: var_min,
+            max: var_max,
+            mean: var_mean,
+            std_dev: var_stdDev,
+            count: var_count,
+            padding_excluded: var_paddingExcluded,
+            padding_pixels: var_paddingPixels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::PrintCalibration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_widthMm = <f64>::sse_decode(deserializer);
+        let mut var_heightMm = <f64>::sse_decode(deserializer);
+        let mut var_rowSpacing = <f64>::sse_decode(deserializer);
+        let mut var_columnSpacing = <f64>::sse_decode(deserializer);
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_atPatient = <bool>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::PrintCalibration {
+            width_mm: var_widthMm,
+            height_mm: var_heightMm,
+            row_spacing: var_rowSpacing,
+            column_spacing: var_columnSpacing,
+            source: var_source,
+            at_patient: var_atPatient,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::ProvenanceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_implementationClassUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_implementationVersionName = <Option<String>>::sse_decode(deserializer);
+        let mut var_sourceApplicationEntityTitle = <Option<String>>::sse_decode(deserializer);
+        let mut var_manufacturer = <Option<String>>::sse_decode(deserializer);
+        let mut var_manufacturerModelName = <Option<String>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::ProvenanceInfo {
+            implementation_class_uid: var_implementationClassUid,
+            implementation_version_name: var_implementationVersionName,
+            source_application_entity_title: var_sourceApplicationEntityTitle,
+            manufacturer: var_manufacturer,
+            manufacturer_model_name: var_manufacturerModelName,
+        };
+    }
+}
+
+impl SseDecode for (f64, f64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <f64>::sse_decode(deserializer);
+        let mut var_field1 = <f64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::Rect {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_x = <u32>::sse_decode(deserializer);
+        let mut var_y = <u32>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::Rect {
+            x: var_x,
+            y: var_y,
+            width: var_width,
+            height: var_height,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::ReferencedInstance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sopClassUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_sopInstanceUid = <String>::sse_decode(deserializer);
+        let mut var_referencedFrames = <Vec<u32>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::ReferencedInstance {
+            sop_class_uid: var_sopClassUid,
+            sop_instance_uid: var_sopInstanceUid,
+            referenced_frames: var_referencedFrames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::RegistrationMatrix {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceFrameOfReferenceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_targetFrameOfReferenceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_matrix = <Vec<f64>>::sse_decode(deserializer);
+        let mut var_matrixType = <Option<String>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::RegistrationMatrix {
+            source_frame_of_reference_uid: var_sourceFrameOfReferenceUid,
+            target_frame_of_reference_uid: var_targetFrameOfReferenceUid,
+            matrix: var_matrix,
+            matrix_type: var_matrixType,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::ResizeFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::dicom_rs_interface::ResizeFilter::Nearest,
+            1 => crate::api::dicom_rs_interface::ResizeFilter::Triangle,
+            2 => crate::api::dicom_rs_interface::ResizeFilter::CatmullRom,
+            3 => crate::api::dicom_rs_interface::ResizeFilter::Lanczos3,
+            _ => unreachable!("Invalid variant for ResizeFilter: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::RetrievedInstance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bytes = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_attempts = <u32>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::RetrievedInstance {
+            bytes: var_bytes,
+            attempts: var_attempts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::RgbaImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_pixelData = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::RgbaImage {
+            width: var_width,
+            height: var_height,
+            pixel_data: var_pixelData,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::ScannedFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_metadata =
+            <Option<crate::api::dicom_rs_interface::DicomMetadata>>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::ScannedFile {
+            path: var_path,
+            metadata: var_metadata,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::SequenceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tag = <String>::sse_decode(deserializer);
+        let mut var_alias = <String>::sse_decode(deserializer);
+        let mut var_itemCount = <u32>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::SequenceInfo {
+            tag: var_tag,
+            alias: var_alias,
+            item_count: var_itemCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::SliceGap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_afterInstance = <u32>::sse_decode(deserializer);
+        let mut var_spacing = <f64>::sse_decode(deserializer);
+        let mut var_missing = <u32>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::SliceGap {
+            after_instance: var_afterInstance,
+            spacing: var_spacing,
+            missing: var_missing,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::StoredPixelPng {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_png = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_rescaleSlope = <f64>::sse_decode(deserializer);
+        let mut var_rescaleIntercept = <f64>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::StoredPixelPng {
+            png: var_png,
+            rescale_slope: var_rescaleSlope,
+            rescale_intercept: var_rescaleIntercept,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::StudyIdentifiers {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_patientId = <Option<String>>::sse_decode(deserializer);
+        let mut var_studyInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_seriesInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_sopInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_modality = <Option<String>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::StudyIdentifiers {
+            patient_id: var_patientId,
+            study_instance_uid: var_studyInstanceUid,
+            series_instance_uid: var_seriesInstanceUid,
+            sop_instance_uid: var_sopInstanceUid,
+            modality: var_modality,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::StudySummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_studyInstanceUid = <Option<String>>::sse_decode(deserializer);
+        let mut var_studyDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_modalities = <Vec<String>>::sse_decode(deserializer);
+        let mut var_seriesCount = <u32>::sse_decode(deserializer);
+        let mut var_instanceCount = <u32>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::StudySummary {
+            study_instance_uid: var_studyInstanceUid,
+            study_date: var_studyDate,
+            modalities: var_modalities,
+            series_count: var_seriesCount,
+            instance_count: var_instanceCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::SuvParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_patientWeightKg = <Option<f64>>::sse_decode(deserializer);
+        let mut var_injectedDoseBq = <f64>::sse_decode(deserializer);
+        let mut var_halfLifeSeconds = <f64>::sse_decode(deserializer);
+        let mut var_injectionTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_injectionDatetime = <Option<String>>::sse_decode(deserializer);
+        let mut var_seriesDate = <Option<String>>::sse_decode(deserializer);
+        let mut var_seriesTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_acquisitionTime = <Option<String>>::sse_decode(deserializer);
+        let mut var_units = <Option<String>>::sse_decode(deserializer);
+        let mut var_decayCorrection = <Option<String>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::SuvParams {
+            patient_weight_kg: var_patientWeightKg,
+            injected_dose_bq: var_injectedDoseBq,
+            half_life_seconds: var_halfLifeSeconds,
+            injection_time: var_injectionTime,
+            injection_datetime: var_injectionDatetime,
+            series_date: var_seriesDate,
+            series_time: var_seriesTime,
+            acquisition_time: var_acquisitionTime,
+            units: var_units,
+            decay_correction: var_decayCorrection,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for [u32; 2] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u32>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::UidSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_studyInstanceUid = <String>::sse_decode(deserializer);
+        let mut var_seriesInstanceUid = <String>::sse_decode(deserializer);
+        let mut var_sopInstanceUid = <String>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::UidSet {
+            study_instance_uid: var_studyInstanceUid,
+            series_instance_uid: var_seriesInstanceUid,
+            sop_instance_uid: var_sopInstanceUid,
+        };
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::UsRegion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minX = <u32>::sse_decode(deserializer);
+        let mut var_minY = <u32>::sse_decode(deserializer);
+        let mut var_maxX = <u32>::sse_decode(deserializer);
+        let mut var_maxY = <u32>::sse_decode(deserializer);
+        let mut var_physicalDeltaX = <f64>::sse_decode(deserializer);
+        let mut var_physicalDeltaY = <f64>::sse_decode(deserializer);
+        let mut var_physicalUnitsX = <u16>::sse_decode(deserializer);
+        let mut var_physicalUnitsY = <u16>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::UsRegion {
+            min_x: var_minX,
+            min_y: var_minY,
+            max_x: var_maxX,
+            max_y: var_maxY,
+            physical_delta_x: var_physicalDeltaX,
+            physical_delta_y: var_physicalDeltaY,
+            physical_units_x: var_physicalUnitsX,
+            physical_units_y: var_physicalUnitsY,
+        };
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::VoiLutMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::dicom_rs_interface::VoiLutMode::Default;
+            }
+            1 => {
+                return crate::api::dicom_rs_interface::VoiLutMode::Identity;
+            }
+            2 => {
+                return crate::api::dicom_rs_interface::VoiLutMode::Normalize;
+            }
+            3 => {
+                let mut var_center = <f64>::sse_decode(deserializer);
+                let mut var_width = <f64>::sse_decode(deserializer);
+                return crate::api::dicom_rs_interface::VoiLutMode::Custom {
+                    center: var_center,
+                    width: var_width,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::VolumePreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_depth = <u32>::sse_decode(deserializer);
+        let mut var_voxels = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::VolumePreview {
             width: var_width,
             height: var_height,
-            bits_allocated: var_bitsAllocated,
-            bits_stored: var_bitsStored,
-            pixel_representation: var_pixelRepresentation,
-            photometric_interpretation: var_photometricInterpretation,
-            samples_per_pixel: var_samplesPerPixel,
-            pixel_data: var_pixelData,
+            depth: var_depth,
+            voxels: var_voxels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::Waveform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_samplingFrequency = <f64>::sse_decode(deserializer);
+        let mut var_channels =
+            <Vec<crate::api::dicom_rs_interface::WaveformChannel>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::Waveform {
+            label: var_label,
+            sampling_frequency: var_samplingFrequency,
+            channels: var_channels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::dicom_rs_interface::WaveformChannel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_units = <Option<String>>::sse_decode(deserializer);
+        let mut var_samples = <Vec<f64>>::sse_decode(deserializer);
+        return crate::api::dicom_rs_interface::WaveformChannel {
+            label: var_label,
+            units: var_units,
+            samples: var_samples,
         };
     }
 }
 
-impl SseDecode for crate::api::dicom_rs_interface::DicomMetadata {
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+                        13 => wire__crate__api__dicom_rs_interface__dicom_handler_anonymize_impl(port, ptr, rust_vec_len, data_len),
+14 => wire__crate__api__dicom_rs_interface__dicom_handler_anonymize_custom_impl(port, ptr, rust_vec_len, data_len),
+15 => wire__crate__api__dicom_rs_interface__dicom_handler_build_volume_preview_impl(port, ptr, rust_vec_len, data_len),
+16 => wire__crate__api__dicom_rs_interface__dicom_handler_check_series_consistency_impl(port, ptr, rust_vec_len, data_len),
+17 => wire__crate__api__dicom_rs_interface__dicom_handler_check_tags_present_impl(port, ptr, rust_vec_len, data_len),
+18 => wire__crate__api__dicom_rs_interface__dicom_handler_count_frames_impl(port, ptr, rust_vec_len, data_len),
+19 => wire__crate__api__dicom_rs_interface__dicom_handler_count_instances_in_dir_impl(port, ptr, rust_vec_len, data_len),
+20 => wire__crate__api__dicom_rs_interface__dicom_handler_create_secondary_capture_impl(port, ptr, rust_vec_len, data_len),
+21 => wire__crate__api__dicom_rs_interface__dicom_handler_create_secondary_capture_from_reference_impl(port, ptr, rust_vec_len, data_len),
+22 => wire__crate__api__dicom_rs_interface__dicom_handler_crop_to_dicom_impl(port, ptr, rust_vec_len, data_len),
+23 => wire__crate__api__dicom_rs_interface__dicom_handler_default_impl(port, ptr, rust_vec_len, data_len),
+24 => wire__crate__api__dicom_rs_interface__dicom_handler_distinct_tag_values_impl(port, ptr, rust_vec_len, data_len),
+25 => wire__crate__api__dicom_rs_interface__dicom_handler_explode_multiframe_impl(port, ptr, rust_vec_len, data_len),
+26 => wire__crate__api__dicom_rs_interface__dicom_handler_export_frame_montage_impl(port, ptr, rust_vec_len, data_len),
+27 => wire__crate__api__dicom_rs_interface__dicom_handler_export_series_to_tiff_impl(port, ptr, rust_vec_len, data_len),
+28 => wire__crate__api__dicom_rs_interface__dicom_handler_extract_icc_profile_impl(port, ptr, rust_vec_len, data_len),
+29 => wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_impl(port, ptr, rust_vec_len, data_len),
+30 => wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_async_impl(port, ptr, rust_vec_len, data_len),
+31 => wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_f32_impl(port, ptr, rust_vec_len, data_len),
+32 => wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_frame_impl(port, ptr, rust_vec_len, data_len),
+33 => wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixels_normalized_impl(port, ptr, rust_vec_len, data_len),
+34 => wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixels_normalized_windowed_impl(port, ptr, rust_vec_len, data_len),
+35 => wire__crate__api__dicom_rs_interface__dicom_handler_generate_mip_impl(port, ptr, rust_vec_len, data_len),
+36 => wire__crate__api__dicom_rs_interface__dicom_handler_generate_uid_impl(port, ptr, rust_vec_len, data_len),
+37 => wire__crate__api__dicom_rs_interface__dicom_handler_generate_uid_set_impl(port, ptr, rust_vec_len, data_len),
+38 => wire__crate__api__dicom_rs_interface__dicom_handler_get_all_elements_recursive_impl(port, ptr, rust_vec_len, data_len),
+39 => wire__crate__api__dicom_rs_interface__dicom_handler_get_compressed_pixel_data_impl(port, ptr, rust_vec_len, data_len),
+40 => wire__crate__api__dicom_rs_interface__dicom_handler_get_elements_by_group_impl(port, ptr, rust_vec_len, data_len),
+41 => wire__crate__api__dicom_rs_interface__dicom_handler_get_encoding_info_impl(port, ptr, rust_vec_len, data_len),
+42 => wire__crate__api__dicom_rs_interface__dicom_handler_get_file_meta_impl(port, ptr, rust_vec_len, data_len),
+43 => wire__crate__api__dicom_rs_interface__dicom_handler_get_frame_increment_impl(port, ptr, rust_vec_len, data_len),
+44 => wire__crate__api__dicom_rs_interface__dicom_handler_get_frames_range_impl(port, ptr, rust_vec_len, data_len),
+45 => wire__crate__api__dicom_rs_interface__dicom_handler_get_hounsfield_impl(port, ptr, rust_vec_len, data_len),
+46 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_impl(port, ptr, rust_vec_len, data_len),
+47 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_16bit_impl(port, ptr, rust_vec_len, data_len),
+48 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_16bit_png_impl(port, ptr, rust_vec_len, data_len),
+49 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_as_impl(port, ptr, rust_vec_len, data_len),
+50 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_async_impl(port, ptr, rust_vec_len, data_len),
+51 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_colormapped_impl(port, ptr, rust_vec_len, data_len),
+52 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_equalized_impl(port, ptr, rust_vec_len, data_len),
+53 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_force_photometric_impl(port, ptr, rust_vec_len, data_len),
+54 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_oriented_impl(port, ptr, rust_vec_len, data_len),
+55 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_partial_impl(port, ptr, rust_vec_len, data_len),
+56 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_with_voi_impl(port, ptr, rust_vec_len, data_len),
+57 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_region_impl(port, ptr, rust_vec_len, data_len),
+58 => wire__crate__api__dicom_rs_interface__dicom_handler_get_key_object_selection_impl(port, ptr, rust_vec_len, data_len),
+59 => wire__crate__api__dicom_rs_interface__dicom_handler_get_metadata_impl(port, ptr, rust_vec_len, data_len),
+60 => wire__crate__api__dicom_rs_interface__dicom_handler_get_metadata_async_impl(port, ptr, rust_vec_len, data_len),
+61 => wire__crate__api__dicom_rs_interface__dicom_handler_get_orientation_labels_impl(port, ptr, rust_vec_len, data_len),
+62 => wire__crate__api__dicom_rs_interface__dicom_handler_get_pet_suv_params_impl(port, ptr, rust_vec_len, data_len),
+63 => wire__crate__api__dicom_rs_interface__dicom_handler_get_pixel_statistics_impl(port, ptr, rust_vec_len, data_len),
+64 => wire__crate__api__dicom_rs_interface__dicom_handler_get_print_calibration_impl(port, ptr, rust_vec_len, data_len),
+65 => wire__crate__api__dicom_rs_interface__dicom_handler_get_provenance_impl(port, ptr, rust_vec_len, data_len),
+66 => wire__crate__api__dicom_rs_interface__dicom_handler_get_referenced_instances_impl(port, ptr, rust_vec_len, data_len),
+67 => wire__crate__api__dicom_rs_interface__dicom_handler_get_registration_matrices_impl(port, ptr, rust_vec_len, data_len),
+68 => wire__crate__api__dicom_rs_interface__dicom_handler_get_rgba_buffer_impl(port, ptr, rust_vec_len, data_len),
+69 => wire__crate__api__dicom_rs_interface__dicom_handler_get_sequences_impl(port, ptr, rust_vec_len, data_len),
+70 => wire__crate__api__dicom_rs_interface__dicom_handler_get_series_cover_impl(port, ptr, rust_vec_len, data_len),
+71 => wire__crate__api__dicom_rs_interface__dicom_handler_get_sr_text_impl(port, ptr, rust_vec_len, data_len),
+72 => wire__crate__api__dicom_rs_interface__dicom_handler_get_study_datetime_unix_impl(port, ptr, rust_vec_len, data_len),
+73 => wire__crate__api__dicom_rs_interface__dicom_handler_get_thumbnail_impl(port, ptr, rust_vec_len, data_len),
+74 => wire__crate__api__dicom_rs_interface__dicom_handler_get_thumbnails_batch_impl(port, ptr, rust_vec_len, data_len),
+75 => wire__crate__api__dicom_rs_interface__dicom_handler_get_ultrasound_regions_impl(port, ptr, rust_vec_len, data_len),
+76 => wire__crate__api__dicom_rs_interface__dicom_handler_get_value_multi_impl(port, ptr, rust_vec_len, data_len),
+77 => wire__crate__api__dicom_rs_interface__dicom_handler_get_waveforms_impl(port, ptr, rust_vec_len, data_len),
+78 => wire__crate__api__dicom_rs_interface__dicom_handler_has_burned_in_annotation_impl(port, ptr, rust_vec_len, data_len),
+79 => wire__crate__api__dicom_rs_interface__dicom_handler_has_pixel_data_impl(port, ptr, rust_vec_len, data_len),
+80 => wire__crate__api__dicom_rs_interface__dicom_handler_is_dicom_file_impl(port, ptr, rust_vec_len, data_len),
+81 => wire__crate__api__dicom_rs_interface__dicom_handler_load_file_impl(port, ptr, rust_vec_len, data_len),
+82 => wire__crate__api__dicom_rs_interface__dicom_handler_load_file_async_impl(port, ptr, rust_vec_len, data_len),
+83 => wire__crate__api__dicom_rs_interface__dicom_handler_load_file_with_image_impl(port, ptr, rust_vec_len, data_len),
+84 => wire__crate__api__dicom_rs_interface__dicom_handler_load_file_with_image_async_impl(port, ptr, rust_vec_len, data_len),
+85 => wire__crate__api__dicom_rs_interface__dicom_handler_merge_study_metadata_impl(port, ptr, rust_vec_len, data_len),
+86 => wire__crate__api__dicom_rs_interface__dicom_handler_new_impl(port, ptr, rust_vec_len, data_len),
+87 => wire__crate__api__dicom_rs_interface__dicom_handler_normalize_date_impl(port, ptr, rust_vec_len, data_len),
+88 => wire__crate__api__dicom_rs_interface__dicom_handler_normalize_time_impl(port, ptr, rust_vec_len, data_len),
+89 => wire__crate__api__dicom_rs_interface__dicom_handler_peek_identifiers_impl(port, ptr, rust_vec_len, data_len),
+90 => wire__crate__api__dicom_rs_interface__dicom_handler_probe_pixel_impl(port, ptr, rust_vec_len, data_len),
+91 => wire__crate__api__dicom_rs_interface__dicom_handler_redact_regions_impl(port, ptr, rust_vec_len, data_len),
+92 => wire__crate__api__dicom_rs_interface__dicom_handler_resample_isotropic_impl(port, ptr, rust_vec_len, data_len),
+93 => wire__crate__api__dicom_rs_interface__dicom_handler_scan_directory_stream_impl(port, ptr, rust_vec_len, data_len),
+94 => wire__crate__api__dicom_rs_interface__dicom_handler_search_tags_impl(port, ptr, rust_vec_len, data_len),
+95 => wire__crate__api__dicom_rs_interface__dicom_handler_split_multiframe_impl(port, ptr, rust_vec_len, data_len),
+96 => wire__crate__api__dicom_rs_interface__dicom_handler_stream_frames_from_path_impl(port, ptr, rust_vec_len, data_len),
+97 => wire__crate__api__dicom_rs_interface__dicom_handler_summarize_study_impl(port, ptr, rust_vec_len, data_len),
+98 => wire__crate__api__dicom_rs_interface__dicom_handler_tags_present_impl(port, ptr, rust_vec_len, data_len),
+99 => wire__crate__api__dicom_rs_interface__dicom_handler_verify_pixel_data_length_impl(port, ptr, rust_vec_len, data_len),
+100 => wire__crate__api__dicom_rs_interface__dicom_handler_wado_rs_retrieve_impl(port, ptr, rust_vec_len, data_len),
+101 => wire__crate__api__dicom_rs_interface__dicom_handler_wado_rs_retrieve_with_retry_impl(port, ptr, rust_vec_len, data_len),
+102 => wire__crate__api__dicom_rs_interface__dicom_handler_write_rgb_image_impl(port, ptr, rust_vec_len, data_len),
+103 => wire__crate__api__dicom_rs_interface__parse_dicom_datetime_impl(port, ptr, rust_vec_len, data_len),
+104 => wire__crate__api__dicom_rs_interface__resize_filter_default_impl(port, ptr, rust_vec_len, data_len),
+105 => wire__crate__api__dicom_rs_interface__supported_transfer_syntaxes_impl(port, ptr, rust_vec_len, data_len),
+                        _ => unreachable!(),
+                    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_tag_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_truncated_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_value_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        4 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_vr_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_tag_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_truncated_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_value_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        8 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_vr_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__dicom_rs_interface__NestedElement_auto_accessor_get_element_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__dicom_rs_interface__NestedElement_auto_accessor_get_path_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__dicom_rs_interface__NestedElement_auto_accessor_set_element_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__dicom_rs_interface__NestedElement_auto_accessor_set_path_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<DicomElement> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<DicomElement> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<DicomElement>> for DicomElement {
+    fn into_into_dart(self) -> FrbWrapper<DicomElement> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<NestedElement> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<NestedElement> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<NestedElement>> for NestedElement {
+    fn into_into_dart(self) -> FrbWrapper<NestedElement> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::Axis {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Axial => 0.into_dart(),
+            Self::Coronal => 1.into_dart(),
+            Self::Sagittal => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::Axis
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::Axis>
+    for crate::api::dicom_rs_interface::Axis
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::Axis {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::ColorMap {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Grayscale => 0.into_dart(),
+            Self::HotIron => 1.into_dart(),
+            Self::Jet => 2.into_dart(),
+            Self::Pet => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::ColorMap
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::ColorMap>
+    for crate::api::dicom_rs_interface::ColorMap
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::ColorMap {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::CompressedPixels {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.transfer_syntax_uid.into_into_dart().into_dart(),
+            self.is_compressed.into_into_dart().into_dart(),
+            self.frames.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::CompressedPixels
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::CompressedPixels>
+    for crate::api::dicom_rs_interface::CompressedPixels
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::CompressedPixels {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::ConsistencyReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.is_consistent.into_into_dart().into_dart(),
+            self.dimension_mismatches.into_into_dart().into_dart(),
+            self.slice_spacings.into_into_dart().into_dart(),
+            self.uniform_spacing.into_into_dart().into_dart(),
+            self.gaps.into_into_dart().into_dart(),
+            self.mixed_orientations.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::ConsistencyReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::ConsistencyReport>
+    for crate::api::dicom_rs_interface::ConsistencyReport
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::ConsistencyReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::dicom_rs_interface::DicomError::Parse(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::Decode(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::Encode(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::TooLarge(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::MissingTag(field0) => {
+                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::UnsupportedTransferSyntax(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::InvalidArgument(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::Io(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::Network(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::DicomError::Other(field0) => {
+                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::DicomError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomError>
+    for crate::api::dicom_rs_interface::DicomError
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.metadata.into_into_dart().into_dart(),
+            self.image.into_into_dart().into_dart(),
+            self.is_valid.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::DicomFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomFile>
+    for crate::api::dicom_rs_interface::DicomFile
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomFloatImage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.number_of_frames.into_into_dart().into_dart(),
+            self.samples_per_pixel.into_into_dart().into_dart(),
+            self.pixel_data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::DicomFloatImage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomFloatImage>
+    for crate::api::dicom_rs_interface::DicomFloatImage
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomFloatImage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomHandler {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.strict.into_into_dart().into_dart(),
+            self.max_decoded_bytes.into_into_dart().into_dart(),
+            self.max_value_length.into_into_dart().into_dart(),
+            self.embed_icc_profile.into_into_dart().into_dart(),
+            self.thread_pool_size.into_into_dart().into_dart(),
+            self.exclude_pixel_padding.into_into_dart().into_dart(),
+            self.window_excludes_padding.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::DicomHandler
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomHandler>
+    for crate::api::dicom_rs_interface::DicomHandler
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomHandler {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomImage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.bits_allocated.into_into_dart().into_dart(),
+            self.bits_stored.into_into_dart().into_dart(),
+            self.pixel_representation.into_into_dart().into_dart(),
+            self.photometric_interpretation.into_into_dart().into_dart(),
+            self.samples_per_pixel.into_into_dart().into_dart(),
+            self.pixel_data.into_into_dart().into_dart(),
+            self.frame_index.into_into_dart().into_dart(),
+            self.bytes_per_frame.into_into_dart().into_dart(),
+            self.applied_invert.into_into_dart().into_dart(),
+            self.applied_window.into_into_dart().into_dart(),
+            self.warnings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::DicomImage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomImage>
+    for crate::api::dicom_rs_interface::DicomImage
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomImage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.patient_name.into_into_dart().into_dart(),
+            self.patient_id.into_into_dart().into_dart(),
+            self.study_date.into_into_dart().into_dart(),
+            self.study_date_normalized.into_into_dart().into_dart(),
+            self.accession_number.into_into_dart().into_dart(),
+            self.referring_physician.into_into_dart().into_dart(),
+            self.modality.into_into_dart().into_dart(),
+            self.study_description.into_into_dart().into_dart(),
+            self.series_description.into_into_dart().into_dart(),
+            self.instance_number.into_into_dart().into_dart(),
+            self.series_number.into_into_dart().into_dart(),
+            self.study_instance_uid.into_into_dart().into_dart(),
+            self.series_instance_uid.into_into_dart().into_dart(),
+            self.sop_instance_uid.into_into_dart().into_dart(),
+            self.frame_of_reference_uid.into_into_dart().into_dart(),
+            self.image_position.into_into_dart().into_dart(),
+            self.pixel_spacing.into_into_dart().into_dart(),
+            self.slice_location.into_into_dart().into_dart(),
+            self.slice_thickness.into_into_dart().into_dart(),
+            self.burned_in_annotation.into_into_dart().into_dart(),
+            self.pixel_aspect_ratio.into_into_dart().into_dart(),
+            self.contrast_bolus_agent.into_into_dart().into_dart(),
+            self.protocol_name.into_into_dart().into_dart(),
+            self.body_part_examined.into_into_dart().into_dart(),
+            self.laterality.into_into_dart().into_dart(),
+            self.view_position.into_into_dart().into_dart(),
+            self.sop_class_uid.into_into_dart().into_dart(),
+            self.sop_class_name.into_into_dart().into_dart(),
+            self.ctdi_vol.into_into_dart().into_dart(),
+            self.exposure.into_into_dart().into_dart(),
+            self.kvp.into_into_dart().into_dart(),
+            self.exposure_time.into_into_dart().into_dart(),
+            self.rdsr_total_dlp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::DicomMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomMetadata>
+    for crate::api::dicom_rs_interface::DicomMetadata
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::EncodingInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.transfer_syntax_uid.into_into_dart().into_dart(),
+            self.is_explicit_vr.into_into_dart().into_dart(),
+            self.is_little_endian.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::EncodingInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::EncodingInfo>
+    for crate::api::dicom_rs_interface::EncodingInfo
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::EncodingInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::FrameIncrement {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tag.into_into_dart().into_dart(),
+            self.alias.into_into_dart().into_dart(),
+            self.values.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::FrameIncrement
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::FrameIncrement>
+    for crate::api::dicom_rs_interface::FrameIncrement
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::FrameIncrement {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::FrameIncrementInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.number_of_frames.into_into_dart().into_dart(),
+            self.increments.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::FrameIncrementInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::FrameIncrementInfo>
+    for crate::api::dicom_rs_interface::FrameIncrementInfo
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::FrameIncrementInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::FrameMontage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.png.into_into_dart().into_dart(),
+            self.cell_width.into_into_dart().into_dart(),
+            self.cell_height.into_into_dart().into_dart(),
+            self.columns.into_into_dart().into_dart(),
+            self.rows.into_into_dart().into_dart(),
+            self.frame_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::FrameMontage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::FrameMontage>
+    for crate::api::dicom_rs_interface::FrameMontage
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::FrameMontage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::ImageOutputFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::dicom_rs_interface::ImageOutputFormat::Png => [0.into_dart()].into_dart(),
+            crate::api::dicom_rs_interface::ImageOutputFormat::Jpeg { quality } => {
+                [1.into_dart(), quality.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::dicom_rs_interface::ImageOutputFormat::WebP => [2.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::ImageOutputFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::ImageOutputFormat>
+    for crate::api::dicom_rs_interface::ImageOutputFormat
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::ImageOutputFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::KeyImage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.study_instance_uid.into_into_dart().into_dart(),
+            self.series_instance_uid.into_into_dart().into_dart(),
+            self.sop_class_uid.into_into_dart().into_dart(),
+            self.sop_instance_uid.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::KeyImage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::KeyImage>
+    for crate::api::dicom_rs_interface::KeyImage
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::KeyImage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::KeyObjectSelection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title_code.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.key_images.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::KeyObjectSelection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::KeyObjectSelection>
+    for crate::api::dicom_rs_interface::KeyObjectSelection
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::KeyObjectSelection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::NormalizedPixels {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.number_of_frames.into_into_dart().into_dart(),
+            self.samples_per_pixel.into_into_dart().into_dart(),
+            self.pixel_data.into_into_dart().into_dart(),
+            self.min.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::NormalizedPixels
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::NormalizedPixels>
+    for crate::api::dicom_rs_interface::NormalizedPixels
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::NormalizedPixels {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::OrientationLabels {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.left.into_into_dart().into_dart(),
+            self.right.into_into_dart().into_dart(),
+            self.top.into_into_dart().into_dart(),
+            self.bottom.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::OrientationLabels
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::OrientationLabels>
+    for crate::api::dicom_rs_interface::OrientationLabels
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::OrientationLabels {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::PartialImage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.png.into_into_dart().into_dart(),
+            self.is_partial.into_into_dart().into_dart(),
+            self.complete_rows.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::PartialImage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::PartialImage>
+    for crate::api::dicom_rs_interface::PartialImage
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::PartialImage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::PixelLengthReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.is_encapsulated.into_into_dart().into_dart(),
+            self.expected.into_into_dart().into_dart(),
+            self.actual.into_into_dart().into_dart(),
+            self.fragment_count.into_into_dart().into_dart(),
+            self.matches.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::PixelLengthReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::PixelLengthReport>
+    for crate::api::dicom_rs_interface::PixelLengthReport
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::PixelLengthReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::PixelStatistics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.min.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+            self.mean.into_into_dart().into_dart(),
+            self.std_dev.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.padding_excluded.into_into_dart().into_dart(),
+            self.padding_pixels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::PixelStatistics
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::PixelStatistics>
+    for crate::api::dicom_rs_interface::PixelStatistics
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::PixelStatistics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::PrintCalibration {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width_mm.into_into_dart().into_dart(),
+            self.height_mm.into_into_dart().into_dart(),
+            self.row_spacing.into_into_dart().into_dart(),
+            self.column_spacing.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.at_patient.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::PrintCalibration
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::PrintCalibration>
+    for crate::api::dicom_rs_interface::PrintCalibration
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::PrintCalibration {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::ProvenanceInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.implementation_class_uid.into_into_dart().into_dart(),
+            self.implementation_version_name
+                .into_into_dart()
+                .into_dart(),
+            self.source_application_entity_title
+                .into_into_dart()
+                .into_dart(),
+            self.manufacturer.into_into_dart().into_dart(),
+            self.manufacturer_model_name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::ProvenanceInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::ProvenanceInfo>
+    for crate::api::dicom_rs_interface::ProvenanceInfo
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::ProvenanceInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::Rect {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::Rect
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::Rect>
+    for crate::api::dicom_rs_interface::Rect
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::Rect {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::ReferencedInstance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sop_class_uid.into_into_dart().into_dart(),
+            self.sop_instance_uid.into_into_dart().into_dart(),
+            self.referenced_frames.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::ReferencedInstance
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::ReferencedInstance>
+    for crate::api::dicom_rs_interface::ReferencedInstance
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::ReferencedInstance {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::RegistrationMatrix {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_frame_of_reference_uid
+                .into_into_dart()
+                .into_dart(),
+            self.target_frame_of_reference_uid
+                .into_into_dart()
+                .into_dart(),
+            self.matrix.into_into_dart().into_dart(),
+            self.matrix_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::RegistrationMatrix
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::RegistrationMatrix>
+    for crate::api::dicom_rs_interface::RegistrationMatrix
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::RegistrationMatrix {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::ResizeFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Nearest => 0.into_dart(),
+            Self::Triangle => 1.into_dart(),
+            Self::CatmullRom => 2.into_dart(),
+            Self::Lanczos3 => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::ResizeFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::ResizeFilter>
+    for crate::api::dicom_rs_interface::ResizeFilter
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::ResizeFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::RetrievedInstance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bytes.into_into_dart().into_dart(),
+            self.attempts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::RetrievedInstance
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::RetrievedInstance>
+    for crate::api::dicom_rs_interface::RetrievedInstance
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::RetrievedInstance {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::RgbaImage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.pixel_data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::RgbaImage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::RgbaImage>
+    for crate::api::dicom_rs_interface::RgbaImage
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::RgbaImage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::ScannedFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::ScannedFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::ScannedFile>
+    for crate::api::dicom_rs_interface::ScannedFile
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::ScannedFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::SequenceInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tag.into_into_dart().into_dart(),
+            self.alias.into_into_dart().into_dart(),
+            self.item_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::SequenceInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::SequenceInfo>
+    for crate::api::dicom_rs_interface::SequenceInfo
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::SequenceInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::SliceGap {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.after_instance.into_into_dart().into_dart(),
+            self.spacing.into_into_dart().into_dart(),
+            self.missing.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::SliceGap
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::SliceGap>
+    for crate::api::dicom_rs_interface::SliceGap
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::SliceGap {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::StoredPixelPng {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.png.into_into_dart().into_dart(),
+            self.rescale_slope.into_into_dart().into_dart(),
+            self.rescale_intercept.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::StoredPixelPng
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::StoredPixelPng>
+    for crate::api::dicom_rs_interface::StoredPixelPng
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::StoredPixelPng {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::StudyIdentifiers {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.patient_id.into_into_dart().into_dart(),
+            self.study_instance_uid.into_into_dart().into_dart(),
+            self.series_instance_uid.into_into_dart().into_dart(),
+            self.sop_instance_uid.into_into_dart().into_dart(),
+            self.modality.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::StudyIdentifiers
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::StudyIdentifiers>
+    for crate::api::dicom_rs_interface::StudyIdentifiers
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::StudyIdentifiers {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::StudySummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.study_instance_uid.into_into_dart().into_dart(),
+            self.study_date.into_into_dart().into_dart(),
+            self.modalities.into_into_dart().into_dart(),
+            self.series_count.into_into_dart().into_dart(),
+            self.instance_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::StudySummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::StudySummary>
+    for crate::api::dicom_rs_interface::StudySummary
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::StudySummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::SuvParams {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.patient_weight_kg.into_into_dart().into_dart(),
+            self.injected_dose_bq.into_into_dart().into_dart(),
+            self.half_life_seconds.into_into_dart().into_dart(),
+            self.injection_time.into_into_dart().into_dart(),
+            self.injection_datetime.into_into_dart().into_dart(),
+            self.series_date.into_into_dart().into_dart(),
+            self.series_time.into_into_dart().into_dart(),
+            self.acquisition_time.into_into_dart().into_dart(),
+            self.units.into_into_dart().into_dart(),
+            self.decay_correction.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::SuvParams
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::SuvParams>
+    for crate::api::dicom_rs_interface::SuvParams
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::SuvParams {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::UidSet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.study_instance_uid.into_into_dart().into_dart(),
+            self.series_instance_uid.into_into_dart().into_dart(),
+            self.sop_instance_uid.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::UidSet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::UidSet>
+    for crate::api::dicom_rs_interface::UidSet
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::UidSet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::UsRegion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.min_x.into_into_dart().into_dart(),
+            self.min_y.into_into_dart().into_dart(),
+            self.max_x.into_into_dart().into_dart(),
+            self.max_y.into_into_dart().into_dart(),
+            self.physical_delta_x.into_into_dart().into_dart(),
+            self.physical_delta_y.into_into_dart().into_dart(),
+            self.physical_units_x.into_into_dart().into_dart(),
+            self.physical_units_y.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::UsRegion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::UsRegion>
+    for crate::api::dicom_rs_interface::UsRegion
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::UsRegion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::VoiLutMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::dicom_rs_interface::VoiLutMode::Default => [0.into_dart()].into_dart(),
+            crate::api::dicom_rs_interface::VoiLutMode::Identity => [1.into_dart()].into_dart(),
+            crate::api::dicom_rs_interface::VoiLutMode::Normalize => [2.into_dart()].into_dart(),
+            crate::api::dicom_rs_interface::VoiLutMode::Custom { center, width } => [
+                3.into_dart(),
+                center.into_into_dart().into_dart(),
+                width.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::VoiLutMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::VoiLutMode>
+    for crate::api::dicom_rs_interface::VoiLutMode
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::VoiLutMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::VolumePreview {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.depth.into_into_dart().into_dart(),
+            self.voxels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::VolumePreview
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::VolumePreview>
+    for crate::api::dicom_rs_interface::VolumePreview
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::VolumePreview {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::Waveform {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.label.into_into_dart().into_dart(),
+            self.sampling_frequency.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::Waveform
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::Waveform>
+    for crate::api::dicom_rs_interface::Waveform
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::Waveform {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::WaveformChannel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.label.into_into_dart().into_dart(),
+            self.units.into_into_dart().into_dart(),
+            self.samples.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::dicom_rs_interface::WaveformChannel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::WaveformChannel>
+    for crate::api::dicom_rs_interface::WaveformChannel
+{
+    fn into_into_dart(self) -> crate::api::dicom_rs_interface::WaveformChannel {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for DicomElement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for NestedElement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::DartOpaque {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.encode(), serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode
+    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        let (ptr, size) = self.sse_encode_raw();
+        <usize>::sse_encode(ptr, serializer);
+        <i32>::sse_encode(size, serializer);
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::Axis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::dicom_rs_interface::Axis::Axial => 0,
+                crate::api::dicom_rs_interface::Axis::Coronal => 1,
+                crate::api::dicom_rs_interface::Axis::Sagittal => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::ColorMap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::dicom_rs_interface::ColorMap::Grayscale => 0,
+                crate::api::dicom_rs_interface::ColorMap::HotIron => 1,
+                crate::api::dicom_rs_interface::ColorMap::Jet => 2,
+                crate::api::dicom_rs_interface::ColorMap::Pet => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::CompressedPixels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.transfer_syntax_uid, serializer);
+        <bool>::sse_encode(self.is_compressed, serializer);
+        <Vec<Vec<u8>>>::sse_encode(self.frames, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::ConsistencyReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_consistent, serializer);
+        <Vec<String>>::sse_encode(self.dimension_mismatches, serializer);
+        <Vec<f64>>::sse_encode(self.slice_spacings, serializer);
+        <bool>::sse_encode(self.uniform_spacing, serializer);
+        <Vec<crate::api::dicom_rs_interface::SliceGap>>::sse_encode(self.gaps, serializer);
+        <bool>::sse_encode(self.mixed_orientations, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::DicomError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::dicom_rs_interface::DicomError::Parse(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::Decode(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::Encode(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::TooLarge(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::MissingTag(field0) => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::UnsupportedTransferSyntax(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::InvalidArgument(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::Io(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::Network(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::dicom_rs_interface::DicomError::Other(field0) => {
+                <i32>::sse_encode(9, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::DicomFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::dicom_rs_interface::DicomMetadata>::sse_encode(self.metadata, serializer);
+        <Option<crate::api::dicom_rs_interface::DicomImage>>::sse_encode(self.image, serializer);
+        <bool>::sse_encode(self.is_valid, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::DicomFloatImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u32>::sse_encode(self.number_of_frames, serializer);
+        <u16>::sse_encode(self.samples_per_pixel, serializer);
+        <Vec<f32>>::sse_encode(self.pixel_data, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::DicomHandler {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.strict, serializer);
+        <Option<u64>>::sse_encode(self.max_decoded_bytes, serializer);
+        <Option<usize>>::sse_encode(self.max_value_length, serializer);
+        <bool>::sse_encode(self.embed_icc_profile, serializer);
+        <Option<usize>>::sse_encode(self.thread_pool_size, serializer);
+        <bool>::sse_encode(self.exclude_pixel_padding, serializer);
+        <bool>::sse_encode(self.window_excludes_padding, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::DicomImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u16>::sse_encode(self.bits_allocated, serializer);
+        <u16>::sse_encode(self.bits_stored, serializer);
+        <u16>::sse_encode(self.pixel_representation, serializer);
+        <String>::sse_encode(self.photometric_interpretation, serializer);
+        <u16>::sse_encode(self.samples_per_pixel, serializer);
+        <Vec<u8>>::sse_encode(self.pixel_data, serializer);
+        <u32>::sse_encode(self.frame_index, serializer);
+        <usize>::sse_encode(self.bytes_per_frame, serializer);
+        <bool>::sse_encode(self.applied_invert, serializer);
+        <Option<(f64, f64)>>::sse_encode(self.applied_window, serializer);
+        <Vec<String>>::sse_encode(self.warnings, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::DicomMetadata {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_patientName = <Option<String>>::sse_decode(deserializer);
-        let mut var_patientId = <Option<String>>::sse_decode(deserializer);
-        let mut var_studyDate = <Option<String>>::sse_decode(deserializer);
-        let mut var_modality = <Option<String>>::sse_decode(deserializer);
-        let mut var_studyDescription = <Option<String>>::sse_decode(deserializer);
-        let mut var_seriesDescription = <Option<String>>::sse_decode(deserializer);
-        let mut var_instanceNumber = <Option<i32>>::sse_decode(deserializer);
-        let mut var_seriesNumber = <Option<i32>>::sse_decode(deserializer);
-        let mut var_studyInstanceUid = <Option<String>>::sse_decode(deserializer);
-        let mut var_seriesInstanceUid = <Option<String>>::sse_decode(deserializer);
-        let mut var_sopInstanceUid = <Option<String>>::sse_decode(deserializer);
-        let mut var_imagePosition = <Option<Vec<f64>>>::sse_decode(deserializer);
-        let mut var_pixelSpacing = <Option<Vec<f64>>>::sse_decode(deserializer);
-        let mut var_sliceLocation = <Option<f64>>::sse_decode(deserializer);
-        let mut var_sliceThickness = <Option<f64>>::sse_decode(deserializer);
-        return crate::api::dicom_rs_interface::DicomMetadata {
-            patient_name: var_patientName,
-            patient_id: var_patientId,
-            study_date: var_studyDate,
-            modality: var_modality,
-            study_description: var_studyDescription,
-            series_description: var_seriesDescription,
-            instance_number: var_instanceNumber,
-            series_number: var_seriesNumber,
-            study_instance_uid: var_studyInstanceUid,
-            series_instance_uid: var_seriesInstanceUid,
-            sop_instance_uid: var_sopInstanceUid,
-            image_position: var_imagePosition,
-            pixel_spacing: var_pixelSpacing,
-            slice_location: var_sliceLocation,
-            slice_thickness: var_sliceThickness,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.patient_name, serializer);
+        <Option<String>>::sse_encode(self.patient_id, serializer);
+        <Option<String>>::sse_encode(self.study_date, serializer);
+        <Option<String>>::sse_encode(self.study_date_normalized, serializer);
+        <Option<String>>::sse_encode(self.accession_number, serializer);
+        <Option<String>>::sse_encode(self.referring_physician, serializer);
+        <Option<String>>::sse_encode(self.modality, serializer);
+        <Option<String>>::sse_encode(self.study_description, serializer);
+        <Option<String>>::sse_encode(self.series_description, serializer);
+        <Option<i32>>::sse_encode(self.instance_number, serializer);
+        <Option<i32>>::sse_encode(self.series_number, serializer);
+        <Option<String>>::sse_encode(self.study_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.series_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.sop_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.frame_of_reference_uid, serializer);
+        <Option<Vec<f64>>>::sse_encode(self.image_position, serializer);
+        <Option<Vec<f64>>>::sse_encode(self.pixel_spacing, serializer);
+        <Option<f64>>::sse_encode(self.slice_location, serializer);
+        <Option<f64>>::sse_encode(self.slice_thickness, serializer);
+        <Option<bool>>::sse_encode(self.burned_in_annotation, serializer);
+        <Option<[u32; 2]>>::sse_encode(self.pixel_aspect_ratio, serializer);
+        <Option<String>>::sse_encode(self.contrast_bolus_agent, serializer);
+        <Option<String>>::sse_encode(self.protocol_name, serializer);
+        <Option<String>>::sse_encode(self.body_part_examined, serializer);
+        <Option<String>>::sse_encode(self.laterality, serializer);
+        <Option<String>>::sse_encode(self.view_position, serializer);
+        <Option<String>>::sse_encode(self.sop_class_uid, serializer);
+        <Option<String>>::sse_encode(self.sop_class_name, serializer);
+        <Option<f64>>::sse_encode(self.ctdi_vol, serializer);
+        <Option<f64>>::sse_encode(self.exposure, serializer);
+        <Option<f64>>::sse_encode(self.kvp, serializer);
+        <Option<f64>>::sse_encode(self.exposure_time, serializer);
+        <Option<f64>>::sse_encode(self.rdsr_total_dlp, serializer);
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode for crate::api::dicom_rs_interface::EncodingInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.transfer_syntax_uid, serializer);
+        <bool>::sse_encode(self.is_explicit_vr, serializer);
+        <bool>::sse_encode(self.is_little_endian, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for f32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for Vec<f64> {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f64>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::FrameIncrement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.tag, serializer);
+        <String>::sse_encode(self.alias, serializer);
+        <Vec<String>>::sse_encode(self.values, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::FrameIncrementInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.number_of_frames, serializer);
+        <Vec<crate::api::dicom_rs_interface::FrameIncrement>>::sse_encode(
+            self.increments,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::FrameMontage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.png, serializer);
+        <u32>::sse_encode(self.cell_width, serializer);
+        <u32>::sse_encode(self.cell_height, serializer);
+        <u32>::sse_encode(self.columns, serializer);
+        <u32>::sse_encode(self.rows, serializer);
+        <u32>::sse_encode(self.frame_count, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::ImageOutputFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::dicom_rs_interface::ImageOutputFormat::Png => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::dicom_rs_interface::ImageOutputFormat::Jpeg { quality } => {
+                <i32>::sse_encode(1, serializer);
+                <u8>::sse_encode(quality, serializer);
+            }
+            crate::api::dicom_rs_interface::ImageOutputFormat::WebP => {
+                <i32>::sse_encode(2, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for isize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_i64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::KeyImage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.study_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.series_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.sop_class_uid, serializer);
+        <String>::sse_encode(self.sop_instance_uid, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::KeyObjectSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.title_code, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Vec<crate::api::dicom_rs_interface::KeyImage>>::sse_encode(self.key_images, serializer);
+    }
+}
+
+impl SseEncode for Vec<DicomElement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <DicomElement>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<NestedElement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <NestedElement>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <bool>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dicom_rs_interface::DicomMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::DicomMetadata>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dicom_rs_interface::FrameIncrement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::FrameIncrement>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dicom_rs_interface::KeyImage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::KeyImage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<u8>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f64>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dicom_rs_interface::Rect> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::Rect>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dicom_rs_interface::ReferencedInstance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::ReferencedInstance>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::dicom_rs_interface::RegistrationMatrix> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::RegistrationMatrix>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::dicom_rs_interface::SequenceInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::SequenceInfo>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::api::dicom_rs_interface::SliceGap> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::SliceGap>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::dicom_rs_interface::DicomImage> {
+impl SseEncode for Vec<crate::api::dicom_rs_interface::UsRegion> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::dicom_rs_interface::DicomImage>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::UsRegion>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<f64> {
+impl SseEncode for Vec<crate::api::dicom_rs_interface::Waveform> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f64>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::Waveform>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<i32> {
+impl SseEncode for Vec<crate::api::dicom_rs_interface::WaveformChannel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::dicom_rs_interface::WaveformChannel>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<Vec<f64>> {
+impl SseEncode for crate::api::dicom_rs_interface::NormalizedPixels {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<f64>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u32>::sse_encode(self.number_of_frames, serializer);
+        <u16>::sse_encode(self.samples_per_pixel, serializer);
+        <Vec<f32>>::sse_encode(self.pixel_data, serializer);
+        <f32>::sse_encode(self.min, serializer);
+        <f32>::sse_encode(self.max, serializer);
     }
 }
 
-impl SseDecode for u16 {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Option<crate::api::dicom_rs_interface::DicomImage> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dicom_rs_interface::DicomImage>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<crate::api::dicom_rs_interface::DicomMetadata> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dicom_rs_interface::DicomMetadata>::sse_encode(value, serializer);
+        }
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        7 => wire__crate__api__dicom_rs_interface__dicom_handler_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => wire__crate__api__dicom_rs_interface__dicom_handler_extract_pixel_data_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__dicom_rs_interface__dicom_handler_get_image_bytes_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__dicom_rs_interface__dicom_handler_get_metadata_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__api__dicom_rs_interface__dicom_handler_is_dicom_file_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        12 => wire__crate__api__dicom_rs_interface__dicom_handler_load_file_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        13 => wire__crate__api__dicom_rs_interface__dicom_handler_load_file_with_image_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        14 => wire__crate__api__dicom_rs_interface__dicom_handler_new_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+impl SseEncode for Option<crate::api::dicom_rs_interface::FrameIncrementInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dicom_rs_interface::FrameIncrementInfo>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_tag_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_value_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_get_vr_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_tag_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_value_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__dicom_rs_interface__DicomElement_auto_accessor_set_vr_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<DicomElement> {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self.0)
-            .into_dart()
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<DicomElement> {}
 
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<DicomElement>> for DicomElement {
-    fn into_into_dart(self) -> FrbWrapper<DicomElement> {
-        self.into()
+impl SseEncode for Option<crate::api::dicom_rs_interface::PrintCalibration> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::dicom_rs_interface::PrintCalibration>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomFile {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.metadata.into_into_dart().into_dart(),
-            self.image.into_into_dart().into_dart(),
-            self.is_valid.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::dicom_rs_interface::DicomFile
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomFile>
-    for crate::api::dicom_rs_interface::DicomFile
-{
-    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomFile {
-        self
+impl SseEncode for Option<(f64, f64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <(f64, f64)>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomHandler {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        Vec::<u8>::new().into_dart()
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::dicom_rs_interface::DicomHandler
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomHandler>
-    for crate::api::dicom_rs_interface::DicomHandler
-{
-    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomHandler {
-        self
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomImage {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.width.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.bits_allocated.into_into_dart().into_dart(),
-            self.bits_stored.into_into_dart().into_dart(),
-            self.pixel_representation.into_into_dart().into_dart(),
-            self.photometric_interpretation.into_into_dart().into_dart(),
-            self.samples_per_pixel.into_into_dart().into_dart(),
-            self.pixel_data.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::dicom_rs_interface::DicomImage
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomImage>
-    for crate::api::dicom_rs_interface::DicomImage
-{
-    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomImage {
-        self
+
+impl SseEncode for Option<Vec<f64>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<f64>>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::dicom_rs_interface::DicomMetadata {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.patient_name.into_into_dart().into_dart(),
-            self.patient_id.into_into_dart().into_dart(),
-            self.study_date.into_into_dart().into_dart(),
-            self.modality.into_into_dart().into_dart(),
-            self.study_description.into_into_dart().into_dart(),
-            self.series_description.into_into_dart().into_dart(),
-            self.instance_number.into_into_dart().into_dart(),
-            self.series_number.into_into_dart().into_dart(),
-            self.study_instance_uid.into_into_dart().into_dart(),
-            self.series_instance_uid.into_into_dart().into_dart(),
-            self.sop_instance_uid.into_into_dart().into_dart(),
-            self.image_position.into_into_dart().into_dart(),
-            self.pixel_spacing.into_into_dart().into_dart(),
-            self.slice_location.into_into_dart().into_dart(),
-            self.slice_thickness.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<u8>>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::dicom_rs_interface::DicomMetadata
-{
+
+impl SseEncode for Option<[u32; 2]> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <[u32; 2]>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::dicom_rs_interface::DicomMetadata>
-    for crate::api::dicom_rs_interface::DicomMetadata
-{
-    fn into_into_dart(self) -> crate::api::dicom_rs_interface::DicomMetadata {
-        self
+
+impl SseEncode for crate::api::dicom_rs_interface::OrientationLabels {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.left, serializer);
+        <String>::sse_encode(self.right, serializer);
+        <String>::sse_encode(self.top, serializer);
+        <String>::sse_encode(self.bottom, serializer);
     }
 }
 
-impl SseEncode for DicomElement {
+impl SseEncode for crate::api::dicom_rs_interface::PartialImage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>>>::sse_encode(flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, MoiArc<_>>(self), serializer);
+        <Vec<u8>>::sse_encode(self.png, serializer);
+        <bool>::sse_encode(self.is_partial, serializer);
+        <u32>::sse_encode(self.complete_rows, serializer);
     }
 }
 
-impl SseEncode
-    for RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>>
-{
+impl SseEncode for crate::api::dicom_rs_interface::PixelLengthReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        let (ptr, size) = self.sse_encode_raw();
-        <usize>::sse_encode(ptr, serializer);
-        <i32>::sse_encode(size, serializer);
+        <bool>::sse_encode(self.is_encapsulated, serializer);
+        <Option<u64>>::sse_encode(self.expected, serializer);
+        <u64>::sse_encode(self.actual, serializer);
+        <Option<u32>>::sse_encode(self.fragment_count, serializer);
+        <bool>::sse_encode(self.matches, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::dicom_rs_interface::PixelStatistics {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <f64>::sse_encode(self.min, serializer);
+        <f64>::sse_encode(self.max, serializer);
+        <f64>::sse_encode(self.mean, serializer);
+        <f64>::sse_encode(self.std_dev, serializer);
+        <u64>::sse_encode(self.count, serializer);
+        <bool>::sse_encode(self.padding_excluded, serializer);
+        <u64>::sse_encode(self.padding_pixels, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::dicom_rs_interface::PrintCalibration {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <f64>::sse_encode(self.width_mm, serializer);
+        <f64>::sse_encode(self.height_mm, serializer);
+        <f64>::sse_encode(self.row_spacing, serializer);
+        <f64>::sse_encode(self.column_spacing, serializer);
+        <String>::sse_encode(self.source, serializer);
+        <bool>::sse_encode(self.at_patient, serializer);
     }
 }
 
-impl SseEncode for crate::api::dicom_rs_interface::DicomFile {
+impl SseEncode for crate::api::dicom_rs_interface::ProvenanceInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::api::dicom_rs_interface::DicomMetadata>::sse_encode(self.metadata, serializer);
-        <Option<crate::api::dicom_rs_interface::DicomImage>>::sse_encode(self.image, serializer);
-        <bool>::sse_encode(self.is_valid, serializer);
+        <Option<String>>::sse_encode(self.implementation_class_uid, serializer);
+        <Option<String>>::sse_encode(self.implementation_version_name, serializer);
+        <Option<String>>::sse_encode(self.source_application_entity_title, serializer);
+        <Option<String>>::sse_encode(self.manufacturer, serializer);
+        <Option<String>>::sse_encode(self.manufacturer_model_name, serializer);
     }
 }
 
-impl SseEncode for crate::api::dicom_rs_interface::DicomHandler {
+impl SseEncode for (f64, f64) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.0, serializer);
+        <f64>::sse_encode(self.1, serializer);
+    }
 }
 
-impl SseEncode for crate::api::dicom_rs_interface::DicomImage {
+impl SseEncode for crate::api::dicom_rs_interface::Rect {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.x, serializer);
+        <u32>::sse_encode(self.y, serializer);
         <u32>::sse_encode(self.width, serializer);
         <u32>::sse_encode(self.height, serializer);
-        <u16>::sse_encode(self.bits_allocated, serializer);
-        <u16>::sse_encode(self.bits_stored, serializer);
-        <u16>::sse_encode(self.pixel_representation, serializer);
-        <String>::sse_encode(self.photometric_interpretation, serializer);
-        <u16>::sse_encode(self.samples_per_pixel, serializer);
-        <Vec<u8>>::sse_encode(self.pixel_data, serializer);
     }
 }
 
-impl SseEncode for crate::api::dicom_rs_interface::DicomMetadata {
+impl SseEncode for crate::api::dicom_rs_interface::ReferencedInstance {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Option<String>>::sse_encode(self.patient_name, serializer);
-        <Option<String>>::sse_encode(self.patient_id, serializer);
-        <Option<String>>::sse_encode(self.study_date, serializer);
-        <Option<String>>::sse_encode(self.modality, serializer);
-        <Option<String>>::sse_encode(self.study_description, serializer);
-        <Option<String>>::sse_encode(self.series_description, serializer);
-        <Option<i32>>::sse_encode(self.instance_number, serializer);
-        <Option<i32>>::sse_encode(self.series_number, serializer);
-        <Option<String>>::sse_encode(self.study_instance_uid, serializer);
-        <Option<String>>::sse_encode(self.series_instance_uid, serializer);
-        <Option<String>>::sse_encode(self.sop_instance_uid, serializer);
-        <Option<Vec<f64>>>::sse_encode(self.image_position, serializer);
-        <Option<Vec<f64>>>::sse_encode(self.pixel_spacing, serializer);
-        <Option<f64>>::sse_encode(self.slice_location, serializer);
-        <Option<f64>>::sse_encode(self.slice_thickness, serializer);
+        <Option<String>>::sse_encode(self.sop_class_uid, serializer);
+        <String>::sse_encode(self.sop_instance_uid, serializer);
+        <Vec<u32>>::sse_encode(self.referenced_frames, serializer);
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for crate::api::dicom_rs_interface::RegistrationMatrix {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        <Option<String>>::sse_encode(self.source_frame_of_reference_uid, serializer);
+        <Option<String>>::sse_encode(self.target_frame_of_reference_uid, serializer);
+        <Vec<f64>>::sse_encode(self.matrix, serializer);
+        <Option<String>>::sse_encode(self.matrix_type, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::dicom_rs_interface::ResizeFilter {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(
+            match self {
+                crate::api::dicom_rs_interface::ResizeFilter::Nearest => 0,
+                crate::api::dicom_rs_interface::ResizeFilter::Triangle => 1,
+                crate::api::dicom_rs_interface::ResizeFilter::CatmullRom => 2,
+                crate::api::dicom_rs_interface::ResizeFilter::Lanczos3 => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<f64> {
+impl SseEncode for crate::api::dicom_rs_interface::RetrievedInstance {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f64>::sse_encode(item, serializer);
-        }
+        <Vec<u8>>::sse_encode(self.bytes, serializer);
+        <u32>::sse_encode(self.attempts, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::dicom_rs_interface::RgbaImage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Vec<u8>>::sse_encode(self.pixel_data, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::dicom_rs_interface::ScannedFile {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.path, serializer);
+        <Option<crate::api::dicom_rs_interface::DicomMetadata>>::sse_encode(
+            self.metadata,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::dicom_rs_interface::DicomImage> {
+impl SseEncode for crate::api::dicom_rs_interface::SequenceInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::dicom_rs_interface::DicomImage>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.tag, serializer);
+        <String>::sse_encode(self.alias, serializer);
+        <u32>::sse_encode(self.item_count, serializer);
     }
 }
 
-impl SseEncode for Option<f64> {
+impl SseEncode for crate::api::dicom_rs_interface::SliceGap {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f64>::sse_encode(value, serializer);
-        }
+        <u32>::sse_encode(self.after_instance, serializer);
+        <f64>::sse_encode(self.spacing, serializer);
+        <u32>::sse_encode(self.missing, serializer);
     }
 }
 
-impl SseEncode for Option<i32> {
+impl SseEncode for crate::api::dicom_rs_interface::StoredPixelPng {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i32>::sse_encode(value, serializer);
-        }
+        <Vec<u8>>::sse_encode(self.png, serializer);
+        <f64>::sse_encode(self.rescale_slope, serializer);
+        <f64>::sse_encode(self.rescale_intercept, serializer);
     }
 }
 
-impl SseEncode for Option<Vec<f64>> {
+impl SseEncode for crate::api::dicom_rs_interface::StudyIdentifiers {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<f64>>::sse_encode(value, serializer);
-        }
+        <Option<String>>::sse_encode(self.patient_id, serializer);
+        <Option<String>>::sse_encode(self.study_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.series_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.sop_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.modality, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::StudySummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.study_instance_uid, serializer);
+        <Option<String>>::sse_encode(self.study_date, serializer);
+        <Vec<String>>::sse_encode(self.modalities, serializer);
+        <u32>::sse_encode(self.series_count, serializer);
+        <u32>::sse_encode(self.instance_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::SuvParams {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.patient_weight_kg, serializer);
+        <f64>::sse_encode(self.injected_dose_bq, serializer);
+        <f64>::sse_encode(self.half_life_seconds, serializer);
+        <Option<String>>::sse_encode(self.injection_time, serializer);
+        <Option<String>>::sse_encode(self.injection_datetime, serializer);
+        <Option<String>>::sse_encode(self.series_date, serializer);
+        <Option<String>>::sse_encode(self.series_time, serializer);
+        <Option<String>>::sse_encode(self.acquisition_time, serializer);
+        <Option<String>>::sse_encode(self.units, serializer);
+        <Option<String>>::sse_encode(self.decay_correction, serializer);
     }
 }
 
@@ -1287,6 +8052,26 @@ impl SseEncode for u32 {
     }
 }
 
+impl SseEncode for [u32; 2] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u32>>::sse_encode(
+            {
+                let boxed: Box<[_]> = Box::new(self);
+                boxed.into_vec()
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1294,11 +8079,34 @@ impl SseEncode for u8 {
     }
 }
 
+impl SseEncode for crate::api::dicom_rs_interface::UidSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.study_instance_uid, serializer);
+        <String>::sse_encode(self.series_instance_uid, serializer);
+        <String>::sse_encode(self.sop_instance_uid, serializer);
+    }
+}
+
 impl SseEncode for () {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::dicom_rs_interface::UsRegion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.min_x, serializer);
+        <u32>::sse_encode(self.min_y, serializer);
+        <u32>::sse_encode(self.max_x, serializer);
+        <u32>::sse_encode(self.max_y, serializer);
+        <f64>::sse_encode(self.physical_delta_x, serializer);
+        <f64>::sse_encode(self.physical_delta_y, serializer);
+        <u16>::sse_encode(self.physical_units_x, serializer);
+        <u16>::sse_encode(self.physical_units_y, serializer);
+    }
+}
+
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1309,6 +8117,62 @@ impl SseEncode for usize {
     }
 }
 
+impl SseEncode for crate::api::dicom_rs_interface::VoiLutMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::dicom_rs_interface::VoiLutMode::Default => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::dicom_rs_interface::VoiLutMode::Identity => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::dicom_rs_interface::VoiLutMode::Normalize => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::dicom_rs_interface::VoiLutMode::Custom { center, width } => {
+                <i32>::sse_encode(3, serializer);
+                <f64>::sse_encode(center, serializer);
+                <f64>::sse_encode(width, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::VolumePreview {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <u32>::sse_encode(self.depth, serializer);
+        <Vec<u8>>::sse_encode(self.voxels, serializer);
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::Waveform {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.label, serializer);
+        <f64>::sse_encode(self.sampling_frequency, serializer);
+        <Vec<crate::api::dicom_rs_interface::WaveformChannel>>::sse_encode(
+            self.channels,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::dicom_rs_interface::WaveformChannel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.units, serializer);
+        <Vec<f64>>::sse_encode(self.samples, serializer);
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.
@@ -1341,6 +8205,20 @@ mod io {
     ) {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>>::decrement_strong_count(ptr as _);
     }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_dicom_rs_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNestedElement(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>>::increment_strong_count(ptr as _);
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_dicom_rs_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNestedElement(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>>::decrement_strong_count(ptr as _);
+    }
 }
 #[cfg(not(target_family = "wasm"))]
 pub use io::*;
@@ -1380,6 +8258,20 @@ mod web {
     ) {
         MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DicomElement>>::decrement_strong_count(ptr as _);
     }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNestedElement(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>>::increment_strong_count(ptr as _);
+    }
+
+    #[wasm_bindgen]
+    pub fn rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNestedElement(
+        ptr: *const std::ffi::c_void,
+    ) {
+        MoiArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NestedElement>>::decrement_strong_count(ptr as _);
+    }
 }
 #[cfg(target_family = "wasm")]
 pub use web::*;